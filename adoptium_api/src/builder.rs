@@ -0,0 +1,132 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use derive_builder::Builder;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, ClientBuilder, Proxy, Url};
+
+use crate::cache::ResponseCache;
+use crate::error::AdoptiumError;
+use crate::retry::RetryPolicy;
+use crate::{Adoptium, Inner};
+
+/// The public Adoptium API
+pub const ADOPTIUM_API_URL: &str = "https://api.adoptium.net/v3/";
+
+fn default_base_url() -> Url {
+    Url::parse(ADOPTIUM_API_URL).expect("ADOPTIUM_API_URL is a valid URL")
+}
+
+/// Settings used to construct an [Adoptium] client. Use [AdoptiumBuilder] to create one.
+#[derive(Debug, Clone, Builder)]
+#[builder(
+    name = "AdoptiumBuilder",
+    public,
+    build_fn(
+        private,
+        name = "try_build",
+        error = "::derive_builder::UninitializedFieldError"
+    )
+)]
+struct AdoptiumSettings {
+    /// The base URL of the API. Defaults to [ADOPTIUM_API_URL]
+    #[builder(default = "default_base_url()")]
+    base_url: Url,
+    #[builder(setter(into, strip_option), default)]
+    user_agent: Option<String>,
+    #[builder(setter(strip_option), default)]
+    timeout: Option<Duration>,
+    #[builder(setter(strip_option), default)]
+    connect_timeout: Option<Duration>,
+    #[builder(setter(strip_option), default)]
+    proxy: Option<Proxy>,
+    #[builder(setter(custom), default)]
+    headers: HeaderMap,
+    /// How failed requests are retried. Defaults to [RetryPolicy::default]
+    #[builder(default)]
+    retry_policy: RetryPolicy,
+    /// Caches responses on disk
+    #[builder(setter(strip_option), default)]
+    cache: Option<ResponseCache>,
+    /// A pre-built client. If set the user agent, timeouts, proxy and headers are ignored
+    #[builder(setter(strip_option), default)]
+    client: Option<Client>,
+}
+
+impl AdoptiumBuilder {
+    /// Replaces the headers sent with every request
+    pub fn headers(&mut self, headers: HeaderMap) -> &mut Self {
+        self.headers = Some(headers);
+        self
+    }
+    /// Adds a header sent with every request
+    pub fn header(&mut self, name: HeaderName, value: HeaderValue) -> &mut Self {
+        self.headers
+            .get_or_insert_with(HeaderMap::new)
+            .insert(name, value);
+        self
+    }
+
+    pub fn build(&self) -> Result<Adoptium, AdoptiumError> {
+        let settings = self.try_build().expect("All fields have defaults");
+        let mut base_url = settings.base_url;
+        if base_url.cannot_be_a_base() {
+            return Err(AdoptiumError::Custom(format!(
+                "{base_url} can not be used as a base URL"
+            )));
+        }
+        if !base_url.path().ends_with('/') {
+            let path = format!("{}/", base_url.path());
+            base_url.set_path(&path);
+        }
+
+        let client = match settings.client {
+            Some(client) => client,
+            None => {
+                let mut builder = ClientBuilder::new().default_headers(settings.headers);
+                if let Some(user_agent) = settings.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(timeout) = settings.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = settings.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(proxy) = settings.proxy {
+                    builder = builder.proxy(proxy);
+                }
+                builder.build()?
+            }
+        };
+        Ok(Adoptium(Arc::new(Inner {
+            client,
+            base_url,
+            retry_policy: settings.retry_policy,
+            cache: settings.cache,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn base_url() {
+        let adoptium = AdoptiumBuilder::default()
+            .base_url(Url::parse("http://localhost:8080/v3").unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            adoptium.build_url("info/available_releases"),
+            "http://localhost:8080/v3/info/available_releases"
+        );
+
+        let adoptium = AdoptiumBuilder::default().build().unwrap();
+        assert_eq!(
+            adoptium.build_url("info/available_releases"),
+            "https://api.adoptium.net/v3/info/available_releases"
+        );
+    }
+}
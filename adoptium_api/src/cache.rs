@@ -0,0 +1,159 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use reqwest::{Client, Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use tracing::{trace, warn};
+
+use crate::error::{AdoptiumError, IntoResult};
+use crate::retry::RetryPolicy;
+
+/// Stores JSON responses on disk keyed by URL.
+///
+/// Entries younger than the TTL are used without a request. Older entries are
+/// revalidated with `If-None-Match` and `If-Modified-Since`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ResponseCache {
+    pub directory: PathBuf,
+    /// How long an entry is used without revalidating it
+    pub ttl: Duration,
+    /// Use an expired entry if the API can not be reached
+    pub serve_stale: bool,
+}
+
+impl ResponseCache {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        ResponseCache {
+            directory: directory.into(),
+            ttl: Duration::from_secs(60 * 60),
+            serve_stale: true,
+        }
+    }
+
+    /// The file an entry for the URL is stored in
+    pub fn entry_path(&self, url: &Url) -> PathBuf {
+        // FNV-1a. Stable across builds unlike DefaultHasher
+        let hash = url
+            .as_str()
+            .bytes()
+            .fold(0xcbf29ce484222325u64, |hash, byte| {
+                (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+            });
+        self.directory.join(format!("{hash:016x}.json"))
+    }
+
+    async fn read(&self, url: &Url) -> Option<CacheEntry> {
+        let path = self.entry_path(url);
+        let value = tokio::fs::read_to_string(&path).await.ok()?;
+        match serde_json::from_str::<CacheEntry>(&value) {
+            // Guards against hash collisions
+            Ok(entry) if entry.url == url.as_str() => Some(entry),
+            Ok(_) => None,
+            Err(error) => {
+                warn!(?path, %error, "Unable to read cache entry");
+                None
+            }
+        }
+    }
+
+    async fn write(&self, url: &Url, entry: &CacheEntry) {
+        let result = async {
+            tokio::fs::create_dir_all(&self.directory).await?;
+            let value = serde_json::to_string(entry)?;
+            tokio::fs::write(self.entry_path(url), value).await?;
+            Ok::<_, std::io::Error>(())
+        }
+        .await;
+        if let Err(error) = result {
+            warn!(%error, "Unable to write cache entry");
+        }
+    }
+
+    /// Returns the body of the URL. Either from the cache or from the API
+    pub async fn get(
+        &self,
+        client: &Client,
+        retry: &RetryPolicy,
+        url: &Url,
+    ) -> Result<String, AdoptiumError> {
+        let entry = self.read(url).await;
+        if let Some(entry) = &entry {
+            let age = (Utc::now() - entry.fetched_at).to_std().unwrap_or_default();
+            if age < self.ttl {
+                trace!(?url, ?age, "Using cached response");
+                return Ok(entry.body.clone());
+            }
+        }
+        let result = retry
+            .retry(|| async {
+                let mut request = client.get(url.clone());
+                if let Some(entry) = &entry {
+                    if let Some(etag) = &entry.etag {
+                        request = request.header(IF_NONE_MATCH, etag);
+                    }
+                    if let Some(last_modified) = &entry.last_modified {
+                        request = request.header(IF_MODIFIED_SINCE, last_modified);
+                    }
+                }
+                let response = request.send().await?;
+                if response.status() == StatusCode::NOT_MODIFIED {
+                    return Ok(None);
+                }
+                let response = response.into_result().await?;
+                CacheEntry::from_response(url, response).await.map(Some)
+            })
+            .await;
+        match (result, entry) {
+            (Ok(Some(entry)), _) => {
+                self.write(url, &entry).await;
+                Ok(entry.body)
+            }
+            (Ok(None), Some(mut entry)) => {
+                trace!(?url, "Cached response not modified");
+                entry.fetched_at = Utc::now();
+                self.write(url, &entry).await;
+                Ok(entry.body)
+            }
+            (Ok(None), None) => Err(AdoptiumError::Custom(format!(
+                "{url} returned Not Modified without a cached response"
+            ))),
+            (Err(error), Some(entry)) if self.serve_stale && RetryPolicy::is_retryable(&error) => {
+                warn!(%error, fetched_at = %entry.fetched_at, "Using stale cached response");
+                Ok(entry.body)
+            }
+            (Err(error), _) => Err(error),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CacheEntry {
+    url: String,
+    etag: Option<String>,
+    last_modified: Option<String>,
+    fetched_at: DateTime<Utc>,
+    body: String,
+}
+
+impl CacheEntry {
+    async fn from_response(url: &Url, response: Response) -> Result<Self, AdoptiumError> {
+        let header = |name| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header(ETAG);
+        let last_modified = header(LAST_MODIFIED);
+        Ok(CacheEntry {
+            url: url.to_string(),
+            etag,
+            last_modified,
+            fetched_at: Utc::now(),
+            body: response.text().await?,
+        })
+    }
+}
@@ -1,5 +1,11 @@
-use reqwest::Response;
+use chrono::{DateTime, Utc};
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Response, StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt::{Display, Formatter};
 use std::num::ParseIntError;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -10,28 +16,150 @@ pub enum AdoptiumError {
     JSONError(#[from] serde_json::Error),
     #[error("Internal Error {0}")]
     Custom(String),
-    #[error("A Bad Response Occurred")]
-    BadResponse(Response),
+    #[error("{0}")]
+    NotFound(Box<ApiError>),
+    #[error("Rate Limited {error}")]
+    RateLimited {
+        error: Box<ApiError>,
+        /// The value of the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
+    #[error("{0}")]
+    ServerError(Box<ApiError>),
+    #[error("{0}")]
+    BadResponse(Box<ApiError>),
     #[error("Invalid URL {0}")]
     InvalidUrl(#[from] url::ParseError),
 }
 
+impl AdoptiumError {
+    /// The API error if the API responded with an error status
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            AdoptiumError::NotFound(error)
+            | AdoptiumError::RateLimited { error, .. }
+            | AdoptiumError::ServerError(error)
+            | AdoptiumError::BadResponse(error) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+    pub fn status(&self) -> Option<StatusCode> {
+        self.api_error().map(|error| error.status)
+    }
+}
+
 impl From<ParseIntError> for AdoptiumError {
     fn from(err: ParseIntError) -> AdoptiumError {
         AdoptiumError::Custom(format!("{}", err))
     }
 }
 
+/// An error status returned by the API
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub url: Url,
+    pub body: ApiErrorBody,
+}
+
+impl Display for ApiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} from {}", self.status, self.url)?;
+        if let Some(message) = &self.body.error_message {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+/// The body of an error response
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct ApiErrorBody {
+    #[serde(rename = "errorMessage", alias = "message", default)]
+    pub error_message: Option<String>,
+    /// Any other fields in the body
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl ApiErrorBody {
+    /// Parses the body as JSON. If it is not JSON the text is used as the message
+    pub fn from_text(text: &str) -> Self {
+        match serde_json::from_str(text) {
+            Ok(body) => body,
+            Err(_) if text.trim().is_empty() => ApiErrorBody::default(),
+            Err(_) => ApiErrorBody {
+                error_message: Some(text.trim().to_string()),
+                other: Map::new(),
+            },
+        }
+    }
+}
+
+/// Parses a `Retry-After` value. Either a number of seconds or an HTTP date
+pub fn parse_retry_after(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or_default(),
+    )
+}
+
 pub trait IntoResult {
-    fn into_result(self) -> Result<Response, AdoptiumError>;
+    async fn into_result(self) -> Result<Response, AdoptiumError>;
 }
 
 impl IntoResult for Response {
-    fn into_result(self) -> Result<Response, AdoptiumError> {
-        if self.status().is_success() {
-            Ok(self)
-        } else {
-            Err(AdoptiumError::BadResponse(self))
+    async fn into_result(self) -> Result<Response, AdoptiumError> {
+        let status = self.status();
+        if status.is_success() {
+            return Ok(self);
         }
+        let url = self.url().clone();
+        let retry_after = self
+            .headers()
+            .get(RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(parse_retry_after);
+        let body = ApiErrorBody::from_text(&self.text().await.unwrap_or_default());
+        let error = Box::new(ApiError { status, url, body });
+        Err(match status {
+            StatusCode::NOT_FOUND => AdoptiumError::NotFound(error),
+            StatusCode::TOO_MANY_REQUESTS => AdoptiumError::RateLimited { error, retry_after },
+            status if status.is_server_error() => AdoptiumError::ServerError(error),
+            _ => AdoptiumError::BadResponse(error),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn error_body() {
+        let body = ApiErrorBody::from_text(r#"{"errorMessage":"No releases match the request"}"#);
+        assert_eq!(
+            body.error_message.as_deref(),
+            Some("No releases match the request")
+        );
+        let body = ApiErrorBody::from_text("Service Unavailable");
+        assert_eq!(body.error_message.as_deref(), Some("Service Unavailable"));
+        assert_eq!(ApiErrorBody::from_text(""), ApiErrorBody::default());
+    }
+
+    #[test]
+    pub fn retry_after() {
+        assert_eq!(parse_retry_after("120"), Some(Duration::from_secs(120)));
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:28:00 GMT"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon"), None);
     }
 }
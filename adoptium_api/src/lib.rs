@@ -3,13 +3,31 @@
 use std::{fmt::Debug, sync::Arc};
 
 use derive_more::{AsRef, Deref};
+use error::IntoResult;
+use requests::available_releases::AvailableReleasesRequest;
+use requests::latest_assets::{LatestAssetsParams, LatestAssetsRequest};
 use requests::release_information::{ReleaseInformationParams, ReleaseInformationRequest};
-use reqwest::{Client, ClientBuilder};
+use requests::release_name::{ReleaseNameParams, ReleaseNameRequest};
+use requests::release_notes::ReleaseNotesRequest;
+use requests::stats::{
+    FeatureVersionDownloadsRequest, MonthlyQueryParams, MonthlyRequest, ReleaseDownloadsRequest,
+    TotalDownloadsRequest, TrackingQueryParams, TrackingRequest,
+};
+use requests::version_range::{VersionRangeParams, VersionRangeRequest};
+use reqwest::{Client, Url};
+use tracing::trace;
 
+pub mod builder;
+pub mod cache;
 pub mod error;
 pub mod requests;
 pub mod response;
+pub mod retry;
 pub mod types;
+pub use builder::{AdoptiumBuilder, ADOPTIUM_API_URL};
+pub use cache::ResponseCache;
+pub use error::AdoptiumError;
+pub use retry::RetryPolicy;
 pub use types::*;
 #[derive(AsRef, Deref, Debug, Clone)]
 pub struct Adoptium(pub Arc<Inner>);
@@ -18,10 +36,17 @@ pub struct Adoptium(pub Arc<Inner>);
 #[doc(hidden)]
 pub struct Inner {
     pub client: Client,
+    pub base_url: Url,
+    pub retry_policy: RetryPolicy,
+    pub cache: Option<ResponseCache>,
 }
 impl Debug for Inner {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("Inner").finish()
+        f.debug_struct("Inner")
+            .field("base_url", &self.base_url.as_str())
+            .field("retry_policy", &self.retry_policy)
+            .field("cache", &self.cache)
+            .finish()
     }
 }
 
@@ -37,16 +62,130 @@ impl Adoptium {
             params: params.into(),
         }
     }
-    pub fn new<V: AsRef<str>>(user_agent: V) -> Adoptium {
-        let client = ClientBuilder::new()
-            .user_agent(user_agent.as_ref())
-            .build()
-            .unwrap();
-        Adoptium(Arc::new(Inner { client }))
+    /// Returns information about a single release by its name
+    /// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/getReleaseInfo)
+    pub fn release_name_request(&self, params: impl Into<ReleaseNameParams>) -> ReleaseNameRequest {
+        ReleaseNameRequest {
+            client: self.clone(),
+            params: params.into(),
+        }
+    }
+    /// Returns release information about versions matching a version or version range
+    /// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/searchReleasesByVersion)
+    pub fn version_range_request(
+        &self,
+        params: impl Into<VersionRangeParams>,
+    ) -> VersionRangeRequest {
+        VersionRangeRequest {
+            client: self.clone(),
+            params: params.into(),
+        }
+    }
+    /// Returns list of latest assets for the given feature version and jvm impl
+    /// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/getLatestAssets)
+    pub fn latest_assets_request(
+        &self,
+        params: impl Into<LatestAssetsParams>,
+    ) -> LatestAssetsRequest {
+        LatestAssetsRequest {
+            client: self.clone(),
+            params: params.into(),
+        }
+    }
+    /// Returns information about available releases
+    /// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Release%20Info/getAvailableReleases)
+    pub fn available_releases_request(&self) -> AvailableReleasesRequest {
+        AvailableReleasesRequest {
+            client: self.clone(),
+        }
+    }
+    /// Returns the issues fixed in a release
+    /// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Release%20Notes/getReleaseNotes)
+    pub fn release_notes_request(
+        &self,
+        release_name: impl Into<String>,
+        vendor: Vendor,
+    ) -> ReleaseNotesRequest {
+        ReleaseNotesRequest {
+            client: self.clone(),
+            release_name: release_name.into(),
+            vendor,
+        }
+    }
+    /// Total downloads of all versions
+    pub fn total_downloads_request(&self) -> TotalDownloadsRequest {
+        TotalDownloadsRequest {
+            client: self.clone(),
+        }
+    }
+    /// Downloads of each release of a feature version
+    pub fn feature_version_downloads_request(
+        &self,
+        feature_version: i64,
+    ) -> FeatureVersionDownloadsRequest {
+        FeatureVersionDownloadsRequest {
+            client: self.clone(),
+            feature_version,
+        }
+    }
+    /// Downloads of each asset of a release
+    pub fn release_downloads_request(
+        &self,
+        feature_version: i64,
+        release_name: impl Into<String>,
+    ) -> ReleaseDownloadsRequest {
+        ReleaseDownloadsRequest {
+            client: self.clone(),
+            feature_version,
+            release_name: release_name.into(),
+        }
+    }
+    /// Daily download totals
+    pub fn download_tracking_request(&self, params: TrackingQueryParams) -> TrackingRequest {
+        TrackingRequest {
+            client: self.clone(),
+            params,
+        }
+    }
+    /// Monthly download totals
+    pub fn monthly_downloads_request(&self, params: MonthlyQueryParams) -> MonthlyRequest {
+        MonthlyRequest {
+            client: self.clone(),
+            params,
+        }
+    }
+    /// Creates a client for the public Adoptium API
+    pub fn new<V: AsRef<str>>(user_agent: V) -> Result<Adoptium, AdoptiumError> {
+        Adoptium::builder().user_agent(user_agent.as_ref()).build()
+    }
+
+    pub fn builder() -> AdoptiumBuilder {
+        AdoptiumBuilder::default()
+    }
+
+    /// Returns the body of the URL. Using the cache if one is configured
+    pub async fn get_text(&self, url: &Url) -> Result<String, AdoptiumError> {
+        if let Some(cache) = &self.cache {
+            return cache.get(&self.client, &self.retry_policy, url).await;
+        }
+        // The body is read inside the retry. So a connection reset while streaming it is retried
+        self.retry_policy
+            .retry(|| async {
+                let response = self
+                    .client
+                    .get(url.clone())
+                    .send()
+                    .await?
+                    .into_result()
+                    .await?;
+                trace!(status=?response.status(), "Response Status");
+                Ok(response.text().await?)
+            })
+            .await
     }
 
-    /// Builds a URL
+    /// Builds a URL relative to the base URL
     pub fn build_url(&self, dest: &str) -> String {
-        format!("https://api.adoptium.net/v3/{}", dest)
+        format!("{}{}", self.base_url, dest)
     }
 }
@@ -0,0 +1,46 @@
+//! Returns information about available releases
+//! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Release%20Info/getAvailableReleases)
+//!
+use crate::requests::AdoptiumRequest;
+use crate::Adoptium;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+#[derive(Clone)]
+pub struct AvailableReleasesRequest {
+    pub client: Adoptium,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct AvailableReleases {
+    /// The versions for which Adoptium Temurin is available and are LTS
+    pub available_lts_releases: Vec<i64>,
+    /// The versions for which Adoptium Temurin is available
+    pub available_releases: Vec<i64>,
+    /// The most recent feature release with a GA build
+    pub most_recent_feature_release: i64,
+    /// The most recent feature version. Including versions only available as EA
+    pub most_recent_feature_version: i64,
+    /// The most recent LTS release with a GA build
+    pub most_recent_lts: i64,
+    /// The version that is currently in development at OpenJDK
+    pub tip_version: i64,
+}
+
+impl AvailableReleases {
+    pub fn is_lts(&self, feature_version: i64) -> bool {
+        self.available_lts_releases.contains(&feature_version)
+    }
+}
+
+impl AdoptiumRequest for AvailableReleasesRequest {
+    type Output = AvailableReleases;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        Cow::Borrowed("info/available_releases")
+    }
+}
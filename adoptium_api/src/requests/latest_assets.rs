@@ -0,0 +1,96 @@
+//! Returns list of latest assets for the given feature version and jvm impl
+//! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/getLatestAssets)
+//!
+use crate::requests::release_information::Binary;
+use crate::requests::AdoptiumRequest;
+use crate::response::VersionData;
+use std::borrow::Cow;
+
+use crate::types::{AdoptiumJvmImpl, Architecture, ImageType, Vendor, OS};
+use crate::Adoptium;
+
+use derive_builder::Builder;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone)]
+pub struct LatestAssetsRequest {
+    pub client: Adoptium,
+    pub params: LatestAssetsParams,
+}
+
+#[derive(Debug, Clone, Builder)]
+pub struct LatestAssetsParams {
+    pub feature_version: i64,
+    #[builder(default)]
+    pub jvm_impl: AdoptiumJvmImpl,
+    #[builder(default)]
+    pub query_params: LatestAssetsQueryParams,
+}
+impl From<i64> for LatestAssetsParams {
+    fn from(feature_version: i64) -> Self {
+        Self {
+            feature_version,
+            jvm_impl: Default::default(),
+            query_params: Default::default(),
+        }
+    }
+}
+impl LatestAssetsParamsBuilder {
+    pub fn with_query_builder(
+        &mut self,
+        query_builder: impl FnOnce(&mut LatestAssetsQueryParamsBuilder),
+    ) -> &mut Self {
+        let mut query_params = LatestAssetsQueryParamsBuilder::default();
+        query_builder(&mut query_params);
+        self.query_params(query_params.build());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+#[builder(
+    default,
+    build_fn(
+        private,
+        name = "try_build",
+        error = "::derive_builder::UninitializedFieldError"
+    )
+)]
+pub struct LatestAssetsQueryParams {
+    pub architecture: Option<Architecture>,
+    pub image_type: Option<ImageType>,
+    pub os: Option<OS>,
+    pub vendor: Option<Vendor>,
+}
+impl LatestAssetsQueryParamsBuilder {
+    pub fn build(&self) -> LatestAssetsQueryParams {
+        self.try_build().expect("Infallible")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LatestAsset {
+    pub binary: Binary,
+    pub release_link: String,
+    pub release_name: String,
+    pub vendor: Vendor,
+    pub version: VersionData,
+}
+
+impl AdoptiumRequest for LatestAssetsRequest {
+    type Output = Vec<LatestAsset>;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        let url = format!(
+            "assets/latest/{}/{}?{}",
+            self.params.feature_version,
+            self.params.jvm_impl,
+            serde_qs::to_string(&self.params.query_params).unwrap()
+        );
+        Cow::Owned(url)
+    }
+}
@@ -1,8 +1,17 @@
+pub mod available_releases;
+pub mod latest_assets;
 pub mod release_information;
+pub mod release_name;
+pub mod release_notes;
+pub mod stats;
+pub mod version_range;
 
-use crate::error::{AdoptiumError, IntoResult};
+use crate::error::AdoptiumError;
 
+use crate::types::Sort;
 use crate::Adoptium;
+use futures_util::stream::{iter, try_unfold};
+use futures_util::{Stream, TryStreamExt};
 use reqwest::Url;
 use serde::de::DeserializeOwned;
 
@@ -24,12 +33,56 @@ pub trait AdoptiumRequest {
 
         let url = Url::parse(full_url.as_str()).map_err(AdoptiumError::from)?;
 
-        let request = client.client.get(url);
-
-        let response = request.send().await?.into_result()?;
-        trace!(status=?response.status(), "Response Status");
-        let data = response.json::<Self::Output>().await?;
+        let body = client.get_text(&url).await?;
+        let data = serde_json::from_str::<Self::Output>(&body)?;
         Ok(data)
     }
 }
 
+/// The largest page size accepted by the Adoptium API
+pub const MAX_PAGE_SIZE: i64 = 20;
+
+/// A request that returns a single page of results at a time
+pub trait PagedAdoptiumRequest: AdoptiumRequest<Output = Vec<Self::Item>> + Sized {
+    type Item: DeserializeOwned;
+
+    /// The sort of the request. Created with the defaults if missing
+    fn sort_mut(&mut self) -> &mut Sort;
+
+    /// Streams the items of every page, starting at the page set in the sort.
+    ///
+    /// Ends when the API returns an empty or partial page or a 404
+    fn stream(mut self) -> impl Stream<Item = Result<Self::Item, AdoptiumError>> {
+        let sort = self.sort_mut();
+        sort.page_size = sort.page_size.clamp(1, MAX_PAGE_SIZE);
+        try_unfold(Some(self), |request| async move {
+            let Some(mut request) = request else {
+                return Ok(None);
+            };
+            let page = match request.execute().await {
+                Ok(page) => page,
+                Err(AdoptiumError::NotFound(_)) => return Ok(None),
+                Err(error) => return Err(error),
+            };
+            if page.is_empty() {
+                return Ok(None);
+            }
+            let sort = request.sort_mut();
+            trace!(page = sort.page, items = page.len(), "Received Page");
+            let next = if (page.len() as i64) < sort.page_size {
+                None
+            } else {
+                sort.page += 1;
+                Some(request)
+            };
+            Ok(Some((page, next)))
+        })
+        .map_ok(|page| iter(page.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Collects the items of every page. See [PagedAdoptiumRequest::stream]
+    async fn execute_paged(self) -> Result<Vec<Self::Item>, AdoptiumError> {
+        self.stream().try_collect().await
+    }
+}
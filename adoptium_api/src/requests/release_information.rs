@@ -1,7 +1,7 @@
 //! Lists of information about builds that match the query
 //! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/searchReleases)
 //!
-use crate::requests::AdoptiumRequest;
+use crate::requests::{AdoptiumRequest, PagedAdoptiumRequest};
 use crate::response::{Package, Source, VersionData};
 use std::borrow::Cow;
 
@@ -11,6 +11,7 @@ use crate::types::{
 };
 use crate::Adoptium;
 
+use chrono::{DateTime, Utc};
 use derive_builder::Builder;
 use serde::{Deserialize, Serialize};
 
@@ -21,26 +22,19 @@ pub struct ReleaseInformationRequest {
 }
 
 #[derive(Debug, Clone, Builder)]
-#[builder(default)]
 pub struct ReleaseInformationParams {
     pub feature_version: i64,
+    #[builder(default)]
     pub release_type: ReleaseType,
+    #[builder(default)]
     pub query_params: ReleaseInformationQueryParams,
 }
-impl Default for ReleaseInformationParams {
-    fn default() -> Self {
-        Self {
-            feature_version: 21,
-            release_type: Default::default(),
-            query_params: Default::default(),
-        }
-    }
-}
 impl From<i64> for ReleaseInformationParams {
     fn from(feature_version: i64) -> Self {
         Self {
             feature_version,
-            ..Default::default()
+            release_type: Default::default(),
+            query_params: Default::default(),
         }
     }
 }
@@ -87,7 +81,7 @@ impl WithSort for ReleaseInformationQueryParamsBuilder {
         self.sort = Some(Some(sort));
     }
 }
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ReleaseInformationDatum {
     pub binaries: Vec<Binary>,
     pub download_count: i64,
@@ -96,13 +90,13 @@ pub struct ReleaseInformationDatum {
     pub release_name: String,
     pub release_type: ReleaseType,
     pub source: Option<Source>,
-    pub timestamp: String,
-    pub updated_at: String,
+    pub timestamp: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
     pub vendor: Vendor,
     pub version_data: VersionData,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Binary {
     pub architecture: Architecture,
     pub download_count: i64,
@@ -113,7 +107,7 @@ pub struct Binary {
     pub package: Package,
     pub project: Project,
     pub scm_ref: String,
-    pub updated_at: String,
+    pub updated_at: DateTime<Utc>,
     pub installer: Option<Package>,
 }
 
@@ -134,3 +128,51 @@ impl AdoptiumRequest for ReleaseInformationRequest {
         Cow::Owned(url)
     }
 }
+
+impl PagedAdoptiumRequest for ReleaseInformationRequest {
+    type Item = ReleaseInformationDatum;
+
+    fn sort_mut(&mut self) -> &mut Sort {
+        self.params
+            .query_params
+            .sort
+            .get_or_insert_with(Sort::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn timestamps() {
+        let release: ReleaseInformationDatum = serde_json::from_str(
+            r#"{
+                "binaries": [{
+                    "architecture": "x64", "download_count": 1, "heap_size": "normal",
+                    "image_type": "jdk", "jvm_impl": "hotspot", "os": "linux", "project": "jdk",
+                    "scm_ref": "jdk-21.0.2+13_adopt", "updated_at": "2024-01-17T08:31:55Z",
+                    "package": {
+                        "download_count": 1, "link": "l", "metadata_link": "m",
+                        "name": "OpenJDK21U-jdk.tar.gz", "size": 1, "checksum_link": null
+                    }
+                }],
+                "download_count": 1, "id": "x", "release_link": "l",
+                "release_name": "jdk-21.0.2+13", "release_type": "ga",
+                "timestamp": "2024-01-16T16:08:24Z", "updated_at": "2024-01-17T08:32:10Z",
+                "vendor": "eclipse",
+                "version_data": {
+                    "major": 21, "minor": 0, "security": 2, "build": 13,
+                    "semver": "21.0.2+13", "openjdk_version": "21.0.2+13-LTS"
+                }
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            release.timestamp,
+            "2024-01-16T16:08:24Z".parse::<DateTime<Utc>>().unwrap()
+        );
+        assert!(release.binaries[0].updated_at > release.timestamp);
+        assert!(release.updated_at > release.binaries[0].updated_at);
+    }
+}
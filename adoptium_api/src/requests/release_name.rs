@@ -0,0 +1,92 @@
+//! Returns information about a single release by its name
+//! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/getReleaseInfo)
+//!
+use crate::requests::release_information::ReleaseInformationDatum;
+use crate::requests::AdoptiumRequest;
+use std::borrow::Cow;
+
+use crate::types::{AdoptiumJvmImpl, CLib, HeapSize, ImageType, Project, SystemProperties, Vendor};
+use crate::Adoptium;
+
+use derive_builder::Builder;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::Serialize;
+
+#[derive(Clone)]
+pub struct ReleaseNameRequest {
+    pub client: Adoptium,
+    pub params: ReleaseNameParams,
+}
+
+#[derive(Debug, Clone, Builder)]
+pub struct ReleaseNameParams {
+    #[builder(default)]
+    pub vendor: Vendor,
+    /// The name of the release. Such as `jdk-21.0.2+13`
+    #[builder(setter(into))]
+    pub release_name: String,
+    #[builder(default)]
+    pub query_params: ReleaseNameQueryParams,
+}
+impl From<&str> for ReleaseNameParams {
+    fn from(release_name: &str) -> Self {
+        Self {
+            vendor: Default::default(),
+            release_name: release_name.to_string(),
+            query_params: Default::default(),
+        }
+    }
+}
+impl ReleaseNameParamsBuilder {
+    pub fn with_query_builder(
+        &mut self,
+        query_builder: impl FnOnce(&mut ReleaseNameQueryParamsBuilder),
+    ) -> &mut Self {
+        let mut query_params = ReleaseNameQueryParamsBuilder::default();
+        query_builder(&mut query_params);
+        self.query_params(query_params.build());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+#[builder(
+    default,
+    build_fn(
+        private,
+        name = "try_build",
+        error = "::derive_builder::UninitializedFieldError"
+    )
+)]
+pub struct ReleaseNameQueryParams {
+    #[serde(flatten)]
+    pub local_system: Option<SystemProperties>,
+    pub heap_size: Option<HeapSize>,
+    pub image_type: Option<ImageType>,
+    pub jvm_impl: Option<AdoptiumJvmImpl>,
+    pub project: Option<Project>,
+    pub c_lib: Option<CLib>,
+}
+impl ReleaseNameQueryParamsBuilder {
+    pub fn build(&self) -> ReleaseNameQueryParams {
+        self.try_build().expect("Infallible")
+    }
+}
+
+impl AdoptiumRequest for ReleaseNameRequest {
+    type Output = ReleaseInformationDatum;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        let url = format!(
+            "assets/release_name/{}/{}?{}",
+            self.params.vendor,
+            utf8_percent_encode(&self.params.release_name, NON_ALPHANUMERIC),
+            serde_qs::to_string(&self.params.query_params).unwrap()
+        );
+        Cow::Owned(url)
+    }
+}
@@ -0,0 +1,113 @@
+//! Returns the issues fixed in a release
+//! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Release%20Notes/getReleaseNotes)
+//!
+use crate::requests::AdoptiumRequest;
+use std::borrow::Cow;
+
+use crate::types::Vendor;
+use crate::Adoptium;
+
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone)]
+pub struct ReleaseNotesRequest {
+    pub client: Adoptium,
+    /// The name of the release. Such as `jdk-21.0.2+13`
+    pub release_name: String,
+    pub vendor: Vendor,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReleaseNotes {
+    pub id: Option<String>,
+    pub release_name: String,
+    pub vendor: Option<Vendor>,
+    pub release_notes: Vec<ReleaseNote>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ReleaseNote {
+    /// The JBS issue id. Such as `JDK-8316304`
+    pub id: String,
+    pub title: String,
+    /// `1` is the highest priority. `5` is the lowest
+    pub priority: Option<String>,
+    pub component: Option<String>,
+    pub subcomponent: Option<String>,
+    #[serde(rename = "type")]
+    pub issue_type: Option<String>,
+    pub link: Option<String>,
+    #[serde(rename = "backportOf")]
+    pub backport_of: Option<String>,
+}
+
+impl ReleaseNote {
+    /// The priority as a number. Accepts both `3` and `P3`
+    pub fn priority_level(&self) -> Option<u8> {
+        let priority = self.priority.as_deref()?.trim();
+        priority
+            .strip_prefix(['P', 'p'])
+            .unwrap_or(priority)
+            .parse()
+            .ok()
+    }
+}
+
+impl AdoptiumRequest for ReleaseNotesRequest {
+    type Output = ReleaseNotes;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        let url = format!(
+            "assets/release_notes/{}?vendor={}",
+            utf8_percent_encode(&self.release_name, NON_ALPHANUMERIC),
+            self.vendor
+        );
+        Cow::Owned(url)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn parse_release_notes() {
+        let notes: ReleaseNotes = serde_json::from_str(
+            r#"{
+                "id": "jdk-17.0.10+7",
+                "release_name": "jdk-17.0.10+7",
+                "vendor": "eclipse",
+                "release_notes": [
+                    {
+                        "id": "JDK-8316304",
+                        "title": "(fs) Add support for BasicFileAttributes.creationTime() for Linux",
+                        "priority": "4",
+                        "component": "core-libs",
+                        "subcomponent": "java.nio",
+                        "type": "Enhancement",
+                        "link": "https://bugs.openjdk.org/browse/JDK-8316304"
+                    },
+                    { "id": "JDK-8314120", "title": "Add tests for FileDescriptor.sync", "priority": "P2" },
+                    { "id": "JDK-8322018", "title": "Test" }
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(notes.vendor, Some(Vendor::Eclipse));
+        let levels = notes
+            .release_notes
+            .iter()
+            .map(ReleaseNote::priority_level)
+            .collect::<Vec<_>>();
+        assert_eq!(levels, vec![Some(4), Some(2), None]);
+        assert_eq!(
+            notes.release_notes[0].issue_type.as_deref(),
+            Some("Enhancement")
+        );
+    }
+}
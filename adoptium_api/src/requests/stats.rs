@@ -0,0 +1,229 @@
+//! Download statistics
+//! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Download%20Statistics)
+//!
+use crate::requests::AdoptiumRequest;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+use crate::types::{AdoptiumJvmImpl, ReleaseType, StatsSource};
+use crate::Adoptium;
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+
+/// Total downloads of all versions
+/// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Download%20Statistics/getTotalDownloadStats)
+#[derive(Clone)]
+pub struct TotalDownloadsRequest {
+    pub client: Adoptium,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TotalDownloads {
+    /// Docker pulls by repository
+    pub docker_pulls: BTreeMap<String, i64>,
+    /// GitHub downloads by feature version
+    pub github_downloads: BTreeMap<i64, i64>,
+    pub total_downloads: TotalDownloadsSummary,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct TotalDownloadsSummary {
+    pub docker_pulls: i64,
+    pub github_downloads: i64,
+    pub total: i64,
+}
+
+impl AdoptiumRequest for TotalDownloadsRequest {
+    type Output = TotalDownloads;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        Cow::Borrowed("stats/downloads/total")
+    }
+}
+
+/// Downloads of each release of a feature version
+/// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Download%20Statistics/getTotalDownloadStatsForVersion)
+#[derive(Clone)]
+pub struct FeatureVersionDownloadsRequest {
+    pub client: Adoptium,
+    pub feature_version: i64,
+}
+
+impl AdoptiumRequest for FeatureVersionDownloadsRequest {
+    /// Downloads by release name
+    type Output = BTreeMap<String, i64>;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("stats/downloads/total/{}", self.feature_version))
+    }
+}
+
+/// Downloads of each asset of a release
+/// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Download%20Statistics/getTotalDownloadStatsForTag)
+#[derive(Clone)]
+pub struct ReleaseDownloadsRequest {
+    pub client: Adoptium,
+    pub feature_version: i64,
+    pub release_name: String,
+}
+
+impl AdoptiumRequest for ReleaseDownloadsRequest {
+    /// Downloads by asset name
+    type Output = BTreeMap<String, i64>;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "stats/downloads/total/{}/{}",
+            self.feature_version,
+            utf8_percent_encode(&self.release_name, NON_ALPHANUMERIC)
+        ))
+    }
+}
+
+/// Daily download totals
+/// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Download%20Statistics/tracking)
+#[derive(Clone)]
+pub struct TrackingRequest {
+    pub client: Adoptium,
+    pub params: TrackingQueryParams,
+}
+
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+#[builder(
+    default,
+    build_fn(
+        private,
+        name = "try_build",
+        error = "::derive_builder::UninitializedFieldError"
+    )
+)]
+pub struct TrackingQueryParams {
+    /// Number of days to display. Defaults to 30
+    pub days: Option<i64>,
+    pub source: Option<StatsSource>,
+    pub feature_version: Option<i64>,
+    pub docker_repo: Option<String>,
+    pub jvm_impl: Option<AdoptiumJvmImpl>,
+    pub release_types: Option<ReleaseType>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+impl TrackingQueryParamsBuilder {
+    pub fn build(&self) -> TrackingQueryParams {
+        self.try_build().expect("Infallible")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DailyDownloads {
+    pub date: DateTime<Utc>,
+    /// Downloads up to the date
+    pub total: i64,
+    /// Downloads on the date
+    pub daily: i64,
+}
+
+impl AdoptiumRequest for TrackingRequest {
+    type Output = Vec<DailyDownloads>;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "stats/downloads/tracking?{}",
+            serde_qs::to_string(&self.params).unwrap()
+        ))
+    }
+}
+
+/// Monthly download totals
+/// [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Download%20Statistics/getMonthlyDownloads)
+#[derive(Clone)]
+pub struct MonthlyRequest {
+    pub client: Adoptium,
+    pub params: MonthlyQueryParams,
+}
+
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+#[builder(
+    default,
+    build_fn(
+        private,
+        name = "try_build",
+        error = "::derive_builder::UninitializedFieldError"
+    )
+)]
+pub struct MonthlyQueryParams {
+    pub source: Option<StatsSource>,
+    pub feature_version: Option<i64>,
+    pub docker_repo: Option<String>,
+    pub jvm_impl: Option<AdoptiumJvmImpl>,
+    /// The last month to include
+    pub to: Option<DateTime<Utc>>,
+}
+impl MonthlyQueryParamsBuilder {
+    pub fn build(&self) -> MonthlyQueryParams {
+        self.try_build().expect("Infallible")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MonthlyDownloads {
+    /// Formatted as `YYYY-MM`
+    pub month: String,
+    /// Downloads up to the end of the month
+    pub total: i64,
+    /// Downloads in the month
+    pub monthly: i64,
+}
+
+impl AdoptiumRequest for MonthlyRequest {
+    type Output = Vec<MonthlyDownloads>;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        Cow::Owned(format!(
+            "stats/downloads/monthly?{}",
+            serde_qs::to_string(&self.params).unwrap()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn feature_versions_are_ordered_numerically() {
+        let total: TotalDownloads = serde_json::from_str(
+            r#"{
+                "docker_pulls": { "eclipse-temurin": 10 },
+                "github_downloads": { "11": 3, "17": 4, "21": 5, "8": 2 },
+                "total_downloads": { "docker_pulls": 10, "github_downloads": 14, "total": 24 }
+            }"#,
+        )
+        .unwrap();
+        let versions = total.github_downloads.keys().copied().collect::<Vec<_>>();
+        assert_eq!(versions, vec![8, 11, 17, 21]);
+    }
+}
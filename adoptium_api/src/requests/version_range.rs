@@ -0,0 +1,299 @@
+//! Returns release information about the specified version
+//! [api.adoptium.net](https://api.adoptium.net/q/swagger-ui/#/Assets/searchReleasesByVersion)
+//!
+use crate::error::AdoptiumError;
+use crate::requests::release_information::ReleaseInformationDatum;
+use crate::requests::{AdoptiumRequest, PagedAdoptiumRequest};
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use crate::types::{
+    AdoptiumJvmImpl, CLib, HeapSize, ImageType, Project, ReleaseType, Sort, SystemProperties,
+    Vendor, WithSort,
+};
+use crate::Adoptium;
+
+use derive_builder::Builder;
+use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+use serde::Serialize;
+
+/// One side of a [VersionRange]
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionBound {
+    Inclusive(String),
+    Exclusive(String),
+    Unbounded,
+}
+
+/// A version or a Maven style version range
+///
+/// ```
+/// use adoptium_api::requests::version_range::VersionRange;
+/// assert_eq!(VersionRange::at_least("17.0.9").to_string(), "[17.0.9,)");
+/// assert_eq!(VersionRange::between("11.0.20", "12").to_string(), "[11.0.20,12)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum VersionRange {
+    /// A single version. Such as `21.0.2+13`
+    Exact(String),
+    Range {
+        lower: VersionBound,
+        upper: VersionBound,
+    },
+}
+
+impl VersionRange {
+    pub fn exact(version: impl Into<String>) -> Self {
+        VersionRange::Exact(version.into())
+    }
+    /// `[version,)`
+    pub fn at_least(version: impl Into<String>) -> Self {
+        VersionRange::Range {
+            lower: VersionBound::Inclusive(version.into()),
+            upper: VersionBound::Unbounded,
+        }
+    }
+    /// `(version,)`
+    pub fn above(version: impl Into<String>) -> Self {
+        VersionRange::Range {
+            lower: VersionBound::Exclusive(version.into()),
+            upper: VersionBound::Unbounded,
+        }
+    }
+    /// `(,version]`
+    pub fn at_most(version: impl Into<String>) -> Self {
+        VersionRange::Range {
+            lower: VersionBound::Unbounded,
+            upper: VersionBound::Inclusive(version.into()),
+        }
+    }
+    /// `(,version)`
+    pub fn below(version: impl Into<String>) -> Self {
+        VersionRange::Range {
+            lower: VersionBound::Unbounded,
+            upper: VersionBound::Exclusive(version.into()),
+        }
+    }
+    /// `[lower,upper)`
+    pub fn between(lower: impl Into<String>, upper: impl Into<String>) -> Self {
+        VersionRange::Range {
+            lower: VersionBound::Inclusive(lower.into()),
+            upper: VersionBound::Exclusive(upper.into()),
+        }
+    }
+    /// Replaces the lower bound. An exact version becomes a range with no upper bound
+    pub fn with_lower(self, lower: VersionBound) -> Self {
+        match self {
+            VersionRange::Exact(_) => VersionRange::Range {
+                lower,
+                upper: VersionBound::Unbounded,
+            },
+            VersionRange::Range { upper, .. } => VersionRange::Range { lower, upper },
+        }
+    }
+    /// Replaces the upper bound. An exact version becomes a range with no lower bound
+    pub fn with_upper(self, upper: VersionBound) -> Self {
+        match self {
+            VersionRange::Exact(_) => VersionRange::Range {
+                lower: VersionBound::Unbounded,
+                upper,
+            },
+            VersionRange::Range { lower, .. } => VersionRange::Range { lower, upper },
+        }
+    }
+}
+
+impl Display for VersionRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VersionRange::Exact(version) => write!(f, "{version}"),
+            VersionRange::Range { lower, upper } => {
+                match lower {
+                    VersionBound::Inclusive(version) => write!(f, "[{version}")?,
+                    VersionBound::Exclusive(version) => write!(f, "({version}")?,
+                    VersionBound::Unbounded => write!(f, "(")?,
+                }
+                write!(f, ",")?;
+                match upper {
+                    VersionBound::Inclusive(version) => write!(f, "{version}]"),
+                    VersionBound::Exclusive(version) => write!(f, "{version})"),
+                    VersionBound::Unbounded => write!(f, ")"),
+                }
+            }
+        }
+    }
+}
+
+impl FromStr for VersionRange {
+    type Err = AdoptiumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let inclusive_lower = match s.chars().next() {
+            Some('[') => true,
+            Some('(') => false,
+            Some(_) => return Ok(VersionRange::Exact(s.to_string())),
+            None => return Err(AdoptiumError::Custom("Empty version range".to_string())),
+        };
+        let inclusive_upper = match s.chars().last() {
+            Some(']') => true,
+            Some(')') => false,
+            _ => {
+                return Err(AdoptiumError::Custom(format!(
+                    "Version range {s} must end with ] or )"
+                )))
+            }
+        };
+        let inner = &s[1..s.len() - 1];
+        let Some((lower, upper)) = inner.split_once(',') else {
+            return if inclusive_lower && inclusive_upper && !inner.trim().is_empty() {
+                Ok(VersionRange::Exact(inner.trim().to_string()))
+            } else {
+                Err(AdoptiumError::Custom(format!(
+                    "Version range {s} is missing a ,"
+                )))
+            };
+        };
+        let bound = |version: &str, inclusive: bool| {
+            let version = version.trim();
+            if version.is_empty() {
+                VersionBound::Unbounded
+            } else if inclusive {
+                VersionBound::Inclusive(version.to_string())
+            } else {
+                VersionBound::Exclusive(version.to_string())
+            }
+        };
+        Ok(VersionRange::Range {
+            lower: bound(lower, inclusive_lower),
+            upper: bound(upper, inclusive_upper),
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct VersionRangeRequest {
+    pub client: Adoptium,
+    pub params: VersionRangeParams,
+}
+
+#[derive(Debug, Clone, Builder)]
+pub struct VersionRangeParams {
+    pub version: VersionRange,
+    #[builder(default)]
+    pub query_params: VersionRangeQueryParams,
+}
+impl From<VersionRange> for VersionRangeParams {
+    fn from(version: VersionRange) -> Self {
+        Self {
+            version,
+            query_params: Default::default(),
+        }
+    }
+}
+impl VersionRangeParamsBuilder {
+    pub fn with_query_builder(
+        &mut self,
+        query_builder: impl FnOnce(&mut VersionRangeQueryParamsBuilder),
+    ) -> &mut Self {
+        let mut query_params = VersionRangeQueryParamsBuilder::default();
+        query_builder(&mut query_params);
+        self.query_params(query_params.build());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Default, Builder)]
+#[builder(
+    default,
+    build_fn(
+        private,
+        name = "try_build",
+        error = "::derive_builder::UninitializedFieldError"
+    )
+)]
+pub struct VersionRangeQueryParams {
+    #[serde(flatten)]
+    pub local_system: Option<SystemProperties>,
+    #[serde(flatten)]
+    pub sort: Option<Sort>,
+    pub heap_size: Option<HeapSize>,
+    pub image_type: Option<ImageType>,
+    pub jvm_impl: Option<AdoptiumJvmImpl>,
+    pub vendor: Option<Vendor>,
+    pub project: Option<Project>,
+    pub c_lib: Option<CLib>,
+    pub release_type: Option<ReleaseType>,
+    /// Only return LTS releases
+    pub lts: Option<bool>,
+    /// Return the version data in semver format instead of the OpenJDK format
+    pub semver: Option<bool>,
+}
+impl VersionRangeQueryParamsBuilder {
+    pub fn build(&self) -> VersionRangeQueryParams {
+        self.try_build().expect("Infallible")
+    }
+}
+impl WithSort for VersionRangeQueryParamsBuilder {
+    fn set_sort(&mut self, sort: Sort) {
+        self.sort = Some(Some(sort));
+    }
+}
+
+impl AdoptiumRequest for VersionRangeRequest {
+    type Output = Vec<ReleaseInformationDatum>;
+
+    fn get_client(&self) -> Adoptium {
+        self.client.clone()
+    }
+
+    fn get_url(&self) -> Cow<'_, str> {
+        let url = format!(
+            "assets/version/{}?{}",
+            utf8_percent_encode(&self.params.version.to_string(), NON_ALPHANUMERIC),
+            serde_qs::to_string(&self.params.query_params).unwrap()
+        );
+        Cow::Owned(url)
+    }
+}
+
+impl PagedAdoptiumRequest for VersionRangeRequest {
+    type Item = ReleaseInformationDatum;
+
+    fn sort_mut(&mut self) -> &mut Sort {
+        self.params
+            .query_params
+            .sort
+            .get_or_insert_with(Sort::default)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn parse_range() {
+        for range in [
+            "[11.0.20,12)",
+            "(,17.0.9]",
+            "[17.0.9,)",
+            "(1.0,2.0)",
+            "21.0.2+13",
+        ] {
+            let parsed = VersionRange::from_str(range).unwrap();
+            assert_eq!(parsed.to_string(), range);
+        }
+        assert_eq!(
+            VersionRange::from_str("[17.0.9,)").unwrap(),
+            VersionRange::at_least("17.0.9")
+        );
+        assert!(VersionRange::from_str("[17.0.9").is_err());
+        assert!(VersionRange::from_str("(17.0.9)").is_err());
+        assert_eq!(
+            VersionRange::from_str("[17.0.9]").unwrap(),
+            VersionRange::exact("17.0.9")
+        );
+    }
+}
@@ -1,22 +1,28 @@
+use crate::error::AdoptiumError;
 use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
-use std::cmp::Ordering::{Equal, Greater, Less};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 #[cfg(test)]
 use tabled::Tabled;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Package {
-    pub checksum: String,
+    /// SHA-256 of the package
+    #[serde(default)]
+    pub checksum: Option<String>,
     pub checksum_link: Option<String>,
     pub download_count: i64,
     pub link: String,
     pub metadata_link: String,
     pub name: String,
+    /// The detached GPG signature of the package
+    #[serde(default)]
+    pub signature_link: Option<String>,
     pub size: i64,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Source {
     link: String,
     name: String,
@@ -24,18 +30,29 @@ pub struct Source {
 }
 
 #[cfg_attr(test, derive(Tabled))]
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct VersionData {
     pub major: i64,
     pub build: i64,
     pub minor: i64,
     pub security: i64,
+    #[cfg_attr(test, tabled(skip))]
+    #[serde(default)]
+    pub patch: Option<i64>,
+    /// The pre-release identifier. Such as `ea` or `beta`
+    #[cfg_attr(test, tabled(skip))]
+    #[serde(default)]
+    pub pre: Option<String>,
     /// Equal to {major}.{minor}.{security}+{build}
     pub semver: String,
     #[cfg_attr(test, tabled(skip))]
     pub openjdk_version: String,
     #[cfg_attr(test, tabled(skip))]
     pub adopt_build_number: Option<i64>,
+    /// Additional build information. Such as `LTS`
+    #[cfg_attr(test, tabled(skip))]
+    #[serde(default)]
+    pub optional: Option<String>,
 }
 
 impl From<(i64, i64, i64, i64)> for VersionData {
@@ -45,16 +62,165 @@ impl From<(i64, i64, i64, i64)> for VersionData {
             build,
             minor,
             security,
+            patch: None,
+            pre: None,
             semver: format!("{major}.{minor}.{security}+{build}"),
             openjdk_version: "".to_string(),
             adopt_build_number: None,
+            optional: None,
+        }
+    }
+}
+
+impl VersionData {
+    /// If the release is a long term support release.
+    ///
+    /// Uses the `LTS` marker in the optional data. Falls back to the release cadence of 8, 11 and every fourth version from 17
+    pub fn is_lts(&self) -> bool {
+        if let Some(optional) = &self.optional {
+            return optional.eq_ignore_ascii_case("LTS");
+        }
+        matches!(self.major, 8 | 11) || (self.major >= 17 && (self.major - 17) % 4 == 0)
+    }
+
+    /// Converts to a [semver::Version].
+    ///
+    /// The build and Adoptium build number are stored in the build metadata which semver ignores when comparing
+    pub fn to_semver(&self) -> semver::Version {
+        let pre = self
+            .pre
+            .as_deref()
+            .and_then(|pre| semver::Prerelease::new(pre).ok())
+            .unwrap_or_default();
+        let build = match self.adopt_build_number {
+            Some(adopt_build_number) => format!("{}.{}", self.build, adopt_build_number),
+            None => self.build.to_string(),
+        };
+        semver::Version {
+            major: self.major as u64,
+            minor: self.minor as u64,
+            patch: self.security as u64,
+            pre,
+            build: semver::BuildMetadata::new(&build).unwrap_or_default(),
+        }
+    }
+
+    /// Parses the legacy Java 8 formats `1.8.0_402-b06` and `8u402-b06`
+    fn parse_legacy(value: &str) -> Option<Result<VersionData, AdoptiumError>> {
+        let (head, update) = value.split_once('_').or_else(|| value.split_once('u'))?;
+        let major = match head.strip_prefix("1.") {
+            Some(rest) => rest.split('.').next()?,
+            None => head,
+        };
+        if major.is_empty() || !major.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+        let (security, build) = match update.split_once("-b") {
+            Some((security, build)) => (security, build),
+            None => (update, "0"),
+        };
+        let result = (|| {
+            let major = major.parse()?;
+            let security = security.parse()?;
+            let build = build.parse()?;
+            Ok(VersionData {
+                openjdk_version: value.to_string(),
+                ..VersionData::from((major, build, 0, security))
+            })
+        })();
+        Some(result)
+    }
+}
+
+impl From<&VersionData> for semver::Version {
+    fn from(value: &VersionData) -> Self {
+        value.to_semver()
+    }
+}
+
+impl FromStr for VersionData {
+    type Err = AdoptiumError;
+
+    /// Parses JEP 322 versions such as `21.0.2+13` and `17.0.10+7-ea`,
+    /// the legacy Java 8 format `1.8.0_402-b06` and release names such as `jdk-21.0.2+13`
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let version = value
+            .strip_prefix("jdk-")
+            .or_else(|| value.strip_prefix("jdk"))
+            .unwrap_or(value);
+        if let Some(result) = VersionData::parse_legacy(version) {
+            return result;
+        }
+        let (version, build) = match version.split_once('+') {
+            Some((version, build)) => (version, Some(build)),
+            None => (version, None),
+        };
+        let (numbers, mut pre) = match version.split_once('-') {
+            Some((numbers, pre)) => (numbers, Some(pre.to_string())),
+            None => (version, None),
+        };
+        let numbers = numbers
+            .split('.')
+            .map(str::parse::<i64>)
+            .collect::<Result<Vec<_>, _>>()?;
+        if numbers.len() > 4 {
+            return Err(AdoptiumError::Custom(format!(
+                "Invalid Java version {value}"
+            )));
+        }
+        let number = |index: usize| numbers.get(index).copied().unwrap_or_default();
+
+        let mut build_number = 0;
+        let mut adopt_build_number = None;
+        let mut optional = None;
+        if let Some(build) = build {
+            let (build, build_optional) = match build.split_once('-') {
+                Some((build, build_optional)) => (build, Some(build_optional)),
+                None => (build, None),
+            };
+            let mut parts = build.split('.');
+            build_number = parts.next().unwrap_or_default().parse()?;
+            let rest = parts.collect::<Vec<_>>();
+            let rest = match rest.first().map(|part| part.parse::<i64>()) {
+                Some(Ok(number)) => {
+                    adopt_build_number = Some(number);
+                    &rest[1..]
+                }
+                _ => &rest[..],
+            };
+            optional = build_optional
+                .map(str::to_string)
+                .or_else(|| (!rest.is_empty()).then(|| rest.join(".")));
         }
+        if pre.is_none()
+            && optional
+                .as_deref()
+                .is_some_and(|optional| ["ea", "beta"].contains(&optional.to_lowercase().as_str()))
+        {
+            pre = optional.take();
+        }
+
+        let mut data = VersionData {
+            major: number(0),
+            build: build_number,
+            minor: number(1),
+            security: number(2),
+            patch: numbers.get(3).copied(),
+            pre,
+            semver: String::new(),
+            openjdk_version: value.to_string(),
+            adopt_build_number,
+            optional,
+        };
+        data.semver = data.to_semver().to_string();
+        Ok(data)
     }
 }
 
 impl PartialEq<Self> for VersionData {
     fn eq(&self, other: &Self) -> bool {
-        self.semver.eq(&other.semver)
+        self.cmp(other) == Ordering::Equal
     }
 }
 
@@ -67,31 +233,43 @@ impl PartialOrd for VersionData {
 }
 
 impl Ord for VersionData {
+    /// Compares the version numbers then the build. A pre-release is lower than the release.
+    /// The Adoptium build number is compared last
     fn cmp(&self, other: &Self) -> Ordering {
-        let values = vec![
-            (self.major, other.major),
-            (self.minor, other.minor),
-            (self.security, other.security),
-            (self.build, other.build),
-        ];
-        for (a, b) in values {
-            match a.cmp(&b) {
-                Less => {
-                    return Less;
-                }
-                Greater => {
-                    return Greater;
-                }
-                _ => {}
-            }
-        }
-        Equal
+        self.major
+            .cmp(&other.major)
+            .then(self.minor.cmp(&other.minor))
+            .then(self.security.cmp(&other.security))
+            .then(
+                self.patch
+                    .unwrap_or_default()
+                    .cmp(&other.patch.unwrap_or_default()),
+            )
+            .then(self.build.cmp(&other.build))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(a), Some(b)) => a.cmp(b),
+            })
+            .then(
+                self.adopt_build_number
+                    .unwrap_or_default()
+                    .cmp(&other.adopt_build_number.unwrap_or_default()),
+            )
+    }
+}
+
+impl Display for VersionData {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.semver)
     }
 }
 
 #[cfg(test)]
 pub mod version_test {
     use crate::response::VersionData;
+    use std::str::FromStr;
     use tabled::{settings::Style, Table};
 
     #[test]
@@ -106,10 +284,52 @@ pub mod version_test {
         vec.sort();
         println!("{}", Table::new(&vec).with(Style::ascii()));
     }
-}
 
-impl Display for VersionData {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.semver)
+    #[test]
+    pub fn parse() {
+        let version = VersionData::from_str("21.0.2+13").unwrap();
+        assert_eq!((version.major, version.minor, version.security), (21, 0, 2));
+        assert_eq!(version.build, 13);
+        assert_eq!(version.semver, "21.0.2+13");
+
+        let version = VersionData::from_str("17.0.10+7-ea").unwrap();
+        assert_eq!(version.security, 10);
+        assert_eq!(version.build, 7);
+        assert_eq!(version.pre.as_deref(), Some("ea"));
+
+        let version = VersionData::from_str("22-beta+27").unwrap();
+        assert_eq!(version.major, 22);
+        assert_eq!(version.pre.as_deref(), Some("beta"));
+
+        let version = VersionData::from_str("21.0.2+13.0.LTS").unwrap();
+        assert_eq!(version.adopt_build_number, Some(0));
+        assert_eq!(version.optional.as_deref(), Some("LTS"));
+
+        for legacy in ["1.8.0_402-b06", "8u402-b06", "jdk8u402-b06"] {
+            let version = VersionData::from_str(legacy).unwrap();
+            assert_eq!(
+                (version.major, version.security, version.build),
+                (8, 402, 6)
+            );
+        }
+        assert_eq!(
+            VersionData::from_str("jdk-21.0.2+13").unwrap(),
+            VersionData::from_str("21.0.2+13").unwrap()
+        );
+        assert!(VersionData::from_str("twenty").is_err());
+    }
+
+    #[test]
+    pub fn ordering() {
+        let parse = |value| VersionData::from_str(value).unwrap();
+        assert!(parse("17.0.10+7-ea") < parse("17.0.10+7"));
+        assert!(parse("17.0.9+9") < parse("17.0.10+7-ea"));
+        assert!(parse("1.8.0_402-b06") < parse("11.0.22+7"));
+        assert!(parse("21.0.2+13") < parse("21.0.2+13.1"));
+        assert_eq!(parse("21.0.2+13"), parse("21.0.2+13-LTS"));
+        assert_eq!(
+            parse("21.0.2+13").to_semver(),
+            semver::Version::parse("21.0.2+13").unwrap()
+        );
     }
 }
@@ -0,0 +1,173 @@
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::error::AdoptiumError;
+
+/// How failed requests are retried.
+///
+/// Rate limited responses, server errors, timeouts and connection errors are retried
+/// with exponential backoff and full jitter. If the API sends a `Retry-After` header it is used instead.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RetryPolicy {
+    /// The total number of attempts. `1` disables retrying
+    pub max_attempts: u32,
+    /// The backoff before the second attempt. Doubled after every attempt
+    #[serde(with = "duration_ms", rename = "initial_backoff_ms")]
+    pub initial_backoff: Duration,
+    /// The largest backoff. Also caps the `Retry-After` header
+    #[serde(with = "duration_ms", rename = "max_backoff_ms")]
+    pub max_backoff: Duration,
+    /// Randomizes the backoff between zero and the computed value
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries
+    pub fn none() -> Self {
+        RetryPolicy {
+            max_attempts: 1,
+            ..Default::default()
+        }
+    }
+
+    /// If the error is worth retrying
+    pub fn is_retryable(error: &AdoptiumError) -> bool {
+        match error {
+            AdoptiumError::RateLimited { .. } | AdoptiumError::ServerError(_) => true,
+            // Body and decode errors are connections that were reset while streaming a body
+            AdoptiumError::ReqwestError(error) => {
+                error.is_timeout() || error.is_connect() || error.is_body() || error.is_decode()
+            }
+            _ => false,
+        }
+    }
+
+    /// The backoff after the given attempt. Starting at 1
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        if self.jitter {
+            let random = RandomState::new().build_hasher().finish();
+            backoff.mul_f64((random % 1000) as f64 / 1000.0)
+        } else {
+            backoff
+        }
+    }
+
+    /// The delay before retrying after the error
+    pub fn delay(&self, attempt: u32, error: &AdoptiumError) -> Duration {
+        match error {
+            AdoptiumError::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } => (*retry_after).min(self.max_backoff),
+            _ => self.backoff(attempt),
+        }
+    }
+
+    /// Calls `request` until it succeeds, returns an error that can not be retried, or runs out of attempts
+    pub async fn retry<T, F, Fut>(&self, mut request: F) -> Result<T, AdoptiumError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, AdoptiumError>>,
+    {
+        let mut attempt = 1;
+        loop {
+            match request().await {
+                Err(error) if attempt < self.max_attempts && Self::is_retryable(&error) => {
+                    let delay = self.delay(attempt, &error);
+                    warn!(%error, ?delay, attempt, "Request failed. Retrying");
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+mod duration_ms {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(duration.as_millis() as u64)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+        u64::deserialize(deserializer).map(Duration::from_millis)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    #[test]
+    pub fn backoff() {
+        let policy = RetryPolicy {
+            jitter: false,
+            ..Default::default()
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(40), Duration::from_secs(30));
+        let policy = RetryPolicy::default();
+        assert!(policy.backoff(2) <= Duration::from_secs(1));
+    }
+
+    #[tokio::test]
+    pub async fn retry() {
+        let policy = RetryPolicy {
+            initial_backoff: Duration::from_millis(1),
+            ..Default::default()
+        };
+        let attempts = AtomicU32::new(0);
+        let result: Result<(), AdoptiumError> = policy
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(AdoptiumError::RateLimited {
+                    error: Box::new(crate::error::ApiError {
+                        status: reqwest::StatusCode::TOO_MANY_REQUESTS,
+                        url: "http://localhost".parse().unwrap(),
+                        body: Default::default(),
+                    }),
+                    retry_after: Some(Duration::ZERO),
+                })
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        attempts.store(0, Ordering::SeqCst);
+        let result: Result<(), AdoptiumError> = policy
+            .retry(|| async {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                Err(AdoptiumError::Custom("Not retryable".to_string()))
+            })
+            .await;
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,5 +1,3 @@
-use crate::types::AdoptiumJvmImpl::HotSpot;
-use crate::types::HeapSize::Normal;
 use derive_builder::Builder;
 use serde::Deserialize;
 use serde::Serialize;
@@ -10,105 +8,118 @@ use tracing::warn;
 
 use strum::{Display, EnumString};
 
-
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum CLib {
     MUSL,
     GLIBC,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Default)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum AdoptiumJvmImpl {
+    #[default]
     HotSpot,
+    OpenJ9,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-impl Default for AdoptiumJvmImpl {
-    fn default() -> Self {
-        HotSpot
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Default)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum HeapSize {
+    #[default]
     Normal,
     Large,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-impl Default for HeapSize {
-    fn default() -> Self {
-        Normal
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Default)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum Project {
+    #[default]
     JDK,
     Valhalla,
     Metropolis,
     JRF,
     Shenandoah,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-impl Default for Project {
-    fn default() -> Self {
-        Project::JDK
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Default)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum ImageType {
+    #[default]
     JDK,
     JRE,
     TestImage,
     DebugImage,
     StaticLibs,
     Sources,
+    SBOM,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-impl Default for ImageType {
-    fn default() -> Self {
-        ImageType::JDK
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Default)]
+#[non_exhaustive]
 pub enum ReleaseType {
+    #[default]
     #[serde(rename = "ga")]
     #[strum(serialize = "ga")]
     GeneralAvailability,
     #[serde(rename = "ea")]
     #[strum(serialize = "ea")]
     EarlyAccess,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-impl Default for ReleaseType {
-    fn default() -> Self {
-        ReleaseType::GeneralAvailability
-    }
-}
-
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Copy)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Default)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum Vendor {
+    #[default]
     Eclipse,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
-#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, Copy, EnumIter)]
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display, EnumIter)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum Architecture {
-    #[strum(serialize = "x64", serialize = "x86_64")]
+    #[strum(to_string = "x64", serialize = "x86_64")]
     X64,
     X86,
     X32,
@@ -119,14 +130,18 @@ pub enum Architecture {
     ARM,
     SparcV9,
     RISCV64,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
 impl Default for Architecture {
     fn default() -> Self {
         match Architecture::from_str(std::env::consts::ARCH) {
-            Ok(value) => value,
-            Err(_error) => {
+            Ok(Architecture::Unknown(_)) | Err(_) => {
                 let supported_architectures = Architecture::iter()
+                    .filter(|v| !matches!(v, Architecture::Unknown(_)))
                     .map(|v| v.to_string())
                     .collect::<Vec<String>>()
                     .join(", ");
@@ -137,6 +152,7 @@ impl Default for Architecture {
                 );
                 panic!("Unsupported Architecture {}", std::env::consts::ARCH)
             }
+            Ok(value) => value,
         }
     }
 }
@@ -144,26 +160,61 @@ impl Default for Architecture {
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
 #[serde(rename_all = "lowercase")]
 #[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
 pub enum OS {
     Linux,
     #[serde(rename = "alpine-linux")]
+    #[strum(serialize = "alpine-linux")]
     AlpineLinux,
     Windows,
+    #[strum(to_string = "mac", serialize = "macos")]
+    Mac,
+    AIX,
+    Solaris,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
+}
+
+/// Where download statistics are counted from
+#[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase")]
+#[non_exhaustive]
+pub enum StatsSource {
+    GitHub,
+    DockerHub,
+    All,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
 #[serde(rename_all = "UPPERCASE")]
+#[non_exhaustive]
 pub enum SortMethod {
     Default,
     Date,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Clone, EnumString, Display)]
+#[non_exhaustive]
 pub enum SortOrder {
     #[serde(rename = "DESC")]
     Descending,
     #[serde(rename = "ASC")]
     Ascending,
+    /// A value unknown to this version of the library
+    #[serde(untagged)]
+    #[strum(default)]
+    Unknown(String),
 }
 
 impl Default for OS {
@@ -216,3 +267,45 @@ impl Default for Sort {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn unknown_round_trip() {
+        let os: OS = serde_json::from_str(r#""plan9""#).unwrap();
+        assert_eq!(os, OS::Unknown("plan9".to_string()));
+        assert_eq!(serde_json::to_string(&os).unwrap(), r#""plan9""#);
+        assert_eq!(os.to_string(), "plan9");
+        assert_eq!(OS::from_str("plan9").unwrap(), os);
+
+        let image_type: ImageType = serde_json::from_str(r#""sbom""#).unwrap();
+        assert_eq!(image_type, ImageType::SBOM);
+        let image_type: ImageType = serde_json::from_str(r#""debugimage""#).unwrap();
+        assert_eq!(image_type, ImageType::DebugImage);
+
+        let jvm_impl: AdoptiumJvmImpl = serde_json::from_str(r#""openj9""#).unwrap();
+        assert_eq!(jvm_impl, AdoptiumJvmImpl::OpenJ9);
+        assert_eq!(jvm_impl.to_string(), "openj9");
+
+        let architecture: Architecture = serde_json::from_str(r#""loongarch64""#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&architecture).unwrap(),
+            r#""loongarch64""#
+        );
+    }
+
+    #[test]
+    pub fn known_values() {
+        assert_eq!(OS::from_str("macos").unwrap(), OS::Mac);
+        assert_eq!(OS::Mac.to_string(), "mac");
+        assert_eq!(OS::AlpineLinux.to_string(), "alpine-linux");
+        assert_eq!(serde_json::to_string(&OS::AIX).unwrap(), r#""aix""#);
+        assert_eq!(Architecture::from_str("x86_64").unwrap(), Architecture::X64);
+        assert_eq!(
+            serde_json::to_string(&ReleaseType::EarlyAccess).unwrap(),
+            r#""ea""#
+        );
+    }
+}
@@ -0,0 +1,223 @@
+use crate::config::InstallSettings;
+use crate::error::InstallerError;
+use adoptium_api::response::VersionData;
+use adoptium_api::types::{
+    AdoptiumJvmImpl, CLib, HeapSize, ImageType, Project, ReleaseType, Vendor,
+};
+
+use async_compression::tokio::bufread::GzipDecoder;
+use async_compression::tokio::write::GzipEncoder;
+use chrono::{DateTime, NaiveDate, Utc};
+use futures_util::StreamExt;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use tokio::fs::{read_dir, File};
+use tokio::io::{AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio_stream::wrappers::ReadDirStream;
+use tokio_tar::Archive;
+
+pub async fn unpack(archive: &Path, location: &Path) -> Result<(), InstallerError> {
+    let decoder = GzipDecoder::new(BufReader::new(File::open(archive).await?));
+    Archive::new(decoder).unpack(location).await?;
+    Ok(())
+}
+
+/// Compresses `source` into `destination`
+pub async fn gzip(source: &Path, destination: &Path) -> Result<(), InstallerError> {
+    let mut encoder = GzipEncoder::new(File::create(destination).await?);
+    tokio::io::copy(&mut File::open(source).await?, &mut encoder).await?;
+    encoder.shutdown().await?;
+    Ok(())
+}
+
+/// The SHA-256 of a file as lowercase hex
+pub async fn sha256(path: &Path) -> Result<String, InstallerError> {
+    let mut file = File::open(path).await?;
+    let mut hasher = Sha256::new();
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+pub async fn verify_sha256(path: &Path, expected: &str) -> Result<(), InstallerError> {
+    let actual = sha256(path).await?;
+    if !actual.eq_ignore_ascii_case(expected) {
+        return Err(InstallerError::ChecksumMismatch {
+            file: path
+                .file_name()
+                .unwrap_or(path.as_os_str())
+                .to_string_lossy()
+                .to_string(),
+            expected: expected.to_string(),
+            actual,
+        });
+    }
+    Ok(())
+}
+
+/// Finds the Java home inside an extracted archive. The directory containing `bin`
+pub async fn find_java_home(extracted: &Path) -> Result<PathBuf, InstallerError> {
+    if extracted.join("bin").exists() {
+        return Ok(extracted.to_path_buf());
+    }
+    let mut stream = ReadDirStream::new(read_dir(extracted).await?);
+    while let Some(value) = stream.next().await {
+        let entry = value?;
+        if entry.metadata().await?.is_dir() && entry.path().join("bin").exists() {
+            return Ok(entry.path());
+        }
+    }
+    Err(InstallerError::InvalidArchive(format!(
+        "No Java home found in {}",
+        extracted.display()
+    )))
+}
+
+/// The `release` file in the root of every JDK and JRE
+#[derive(Debug, Clone, Default)]
+pub struct ReleaseFile {
+    pub properties: HashMap<String, String>,
+}
+
+impl FromStr for ReleaseFile {
+    type Err = InstallerError;
+
+    /// Parses the `KEY="VALUE"` lines
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let properties = value
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(key, value)| {
+                (
+                    key.trim().to_string(),
+                    value.trim().trim_matches('"').to_string(),
+                )
+            })
+            .collect();
+        Ok(ReleaseFile { properties })
+    }
+}
+
+impl ReleaseFile {
+    pub async fn read(java_home: &Path) -> Result<ReleaseFile, InstallerError> {
+        let path = java_home.join("release");
+        if !path.exists() {
+            return Err(InstallerError::InvalidArchive(format!(
+                "{} does not exist",
+                path.display()
+            )));
+        }
+        tokio::fs::read_to_string(path).await?.parse()
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.properties
+            .get(key)
+            .map(String::as_str)
+            .filter(|value| !value.is_empty())
+    }
+
+    pub fn version_data(&self) -> Result<VersionData, InstallerError> {
+        let version = [
+            "JAVA_RUNTIME_VERSION",
+            "FULL_VERSION",
+            "SEMANTIC_VERSION",
+            "JAVA_VERSION",
+        ]
+        .into_iter()
+        .find_map(|key| self.get(key))
+        .ok_or_else(|| {
+            InstallerError::InvalidArchive("The release file has no version".to_string())
+        })?;
+        VersionData::from_str(version).map_err(InstallerError::from)
+    }
+
+    /// When the build was released. `JAVA_VERSION_DATE`
+    pub fn release_date(&self) -> Option<DateTime<Utc>> {
+        let date = NaiveDate::parse_from_str(self.get("JAVA_VERSION_DATE")?, "%Y-%m-%d").ok()?;
+        Some(date.and_hms_opt(0, 0, 0)?.and_utc())
+    }
+
+    pub fn install_settings(&self, version: &VersionData) -> InstallSettings {
+        let vendor = match self.get("IMPLEMENTOR") {
+            None => Vendor::default(),
+            Some(implementor) if implementor.contains("Adoptium") => Vendor::Eclipse,
+            Some(implementor) => Vendor::Unknown(implementor.to_string()),
+        };
+        let c_lib = match self.get("LIBC") {
+            Some("gnu") => Some(CLib::GLIBC),
+            Some("musl") => Some(CLib::MUSL),
+            _ => None,
+        };
+        let release_type = match &version.pre {
+            Some(pre) if pre.eq_ignore_ascii_case("ea") => ReleaseType::EarlyAccess,
+            _ => ReleaseType::GeneralAvailability,
+        };
+        InstallSettings {
+            heap_size: HeapSize::Normal,
+            image_type: self
+                .get("IMAGE_TYPE")
+                .map(|value| ImageType::from_str(&value.to_lowercase()).unwrap_or_default())
+                .unwrap_or_default(),
+            jvm_impl: self
+                .get("JVM_VARIANT")
+                .map(|value| AdoptiumJvmImpl::from_str(&value.to_lowercase()).unwrap_or_default())
+                .unwrap_or_default(),
+            vendor,
+            project: Project::JDK,
+            c_lib,
+            release_type,
+            feature_version: version.major,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn parse_release_file() {
+        let release: ReleaseFile = r#"IMPLEMENTOR="Eclipse Adoptium"
+IMPLEMENTOR_VERSION="Temurin-21.0.2+13"
+JAVA_RUNTIME_VERSION="21.0.2+13-LTS"
+JAVA_VERSION="21.0.2"
+JAVA_VERSION_DATE="2024-01-16"
+LIBC="gnu"
+OS_ARCH="x86_64"
+OS_NAME="Linux"
+FULL_VERSION="21.0.2+13-LTS"
+SEMANTIC_VERSION="21.0.2+13"
+JVM_VARIANT="Hotspot"
+IMAGE_TYPE="JDK"
+"#
+        .parse()
+        .unwrap();
+        let version = release.version_data().unwrap();
+        assert_eq!(version.major, 21);
+        assert_eq!(version.security, 2);
+        assert_eq!(version.build, 13);
+        let settings = release.install_settings(&version);
+        assert_eq!(settings.vendor, Vendor::Eclipse);
+        assert_eq!(settings.image_type, ImageType::JDK);
+        assert_eq!(settings.jvm_impl, AdoptiumJvmImpl::HotSpot);
+        assert_eq!(settings.c_lib, Some(CLib::GLIBC));
+        assert_eq!(settings.release_type, ReleaseType::GeneralAvailability);
+        assert_eq!(
+            release
+                .release_date()
+                .unwrap()
+                .format("%Y-%m-%d")
+                .to_string(),
+            "2024-01-16"
+        );
+    }
+}
@@ -0,0 +1,327 @@
+use crate::archive::{self, unpack};
+use crate::commands::install::register;
+use crate::config::{InstallConfig, InstallSettings};
+use crate::download::{client, download_archive};
+use crate::error::InstallerError;
+use crate::signature::{fetch_signature, SignatureVerifier};
+use crate::sys::SysInstaller;
+use crate::Installer;
+use adoptium_api::requests::release_information::{
+    ReleaseInformationDatum, ReleaseInformationParams, ReleaseInformationParamsBuilder,
+};
+use adoptium_api::requests::AdoptiumRequest;
+use adoptium_api::types::{
+    AdoptiumJvmImpl, HeapSize, ImageType, Project, ReleaseType, SortMethod, SortOrder,
+    SystemProperties, Vendor, WithSort,
+};
+
+use async_compression::tokio::write::GzipEncoder;
+use chrono::{DateTime, Utc};
+use clap::{Args, Subcommand};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+use tokio::fs::{copy, create_dir_all, remove_dir_all, File};
+use tokio::io::AsyncWriteExt;
+use tokio_tar::Builder;
+
+/// The name of the manifest in the root of a bundle
+pub const BUNDLE_MANIFEST: &str = "bundle.json";
+/// The name of the copy of the keyring in the root of a bundle
+pub const BUNDLE_KEYRING: &str = "keyring.gpg";
+
+#[derive(Args)]
+pub struct BundleCommand {
+    #[clap(subcommand)]
+    pub bundle: Bundle,
+}
+
+#[derive(Subcommand)]
+pub enum Bundle {
+    /// Downloads releases into a bundle for hosts without internet access
+    Create {
+        /// A directory or a `.tar.gz` file
+        output: PathBuf,
+        #[clap(short, long)]
+        /// A Java Version. Can be repeated
+        version: Vec<i64>,
+        #[clap(long)]
+        /// Includes the latest release of every current install
+        installed: bool,
+        #[clap(short, long)]
+        /// Defaults to JDK
+        image_type: Option<ImageType>,
+        #[clap(short, long)]
+        jvm_impl: Option<AdoptiumJvmImpl>,
+        #[clap(short, long)]
+        /// Defaults to GA
+        release_type: Option<ReleaseType>,
+    },
+    /// Installs every release of a bundle
+    Install {
+        /// A bundle directory or `.tar.gz` file
+        bundle: PathBuf,
+        #[clap(long)]
+        /// Installs even if the signature of a package can not be verified
+        allow_unverified: bool,
+    },
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundleManifest {
+    pub created: DateTime<Utc>,
+    pub entries: Vec<BundleEntry>,
+    /// The file name of the keyring to verify the signatures with on hosts without one
+    #[serde(default)]
+    pub keyring: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct BundleEntry {
+    /// The release with only the bundled binary
+    pub release: ReleaseInformationDatum,
+    /// The file name of the archive
+    pub archive: String,
+    /// SHA-256 of the archive
+    pub checksum: String,
+    /// The file name of the detached signature
+    pub signature: Option<String>,
+}
+
+pub async fn execute(app: Installer, bundle: BundleCommand) -> Result<(), InstallerError> {
+    match bundle.bundle {
+        Bundle::Create {
+            output,
+            version,
+            installed,
+            image_type,
+            jvm_impl,
+            release_type,
+        } => {
+            let mut settings = version
+                .into_iter()
+                .map(|feature_version| InstallSettings {
+                    heap_size: HeapSize::Normal,
+                    image_type: image_type.clone().unwrap_or_default(),
+                    jvm_impl: jvm_impl.clone().unwrap_or_default(),
+                    vendor: Vendor::default(),
+                    project: Project::JDK,
+                    c_lib: None,
+                    release_type: release_type.clone().unwrap_or_default(),
+                    feature_version,
+                })
+                .collect::<Vec<_>>();
+            if installed {
+                settings.extend(
+                    app.installs
+                        .iter()
+                        .map(|install| install.config.install_settings.clone()),
+                );
+            }
+            if settings.is_empty() {
+                return Err(InstallerError::Custom(
+                    "Specify at least one version or --installed".to_string(),
+                ));
+            }
+            create(&app, &settings, &output).await
+        }
+        Bundle::Install {
+            bundle,
+            allow_unverified,
+        } => install(app, &bundle, allow_unverified).await,
+    }
+}
+
+fn request(settings: &InstallSettings) -> ReleaseInformationParams {
+    ReleaseInformationParamsBuilder::default()
+        .feature_version(settings.feature_version)
+        .release_type(settings.release_type.clone())
+        .with_query_builder(|query| {
+            query
+                .heap_size(Some(settings.heap_size.clone()))
+                .image_type(Some(settings.image_type.clone()))
+                .jvm_impl(Some(settings.jvm_impl.clone()))
+                .vendor(Some(settings.vendor.clone()))
+                .local_system(Some(SystemProperties::default()))
+                .with_sort(|sort| {
+                    sort.sort_order(SortOrder::Descending)
+                        .sort_method(SortMethod::Default)
+                        .page(0)
+                        .page_size(1);
+                });
+        })
+        .build()
+        .expect("Failed to build ReleaseInformationParams")
+}
+
+async fn create(
+    app: &Installer,
+    settings: &[InstallSettings],
+    output: &Path,
+) -> Result<(), InstallerError> {
+    let tarball = output.to_string_lossy().ends_with(".tar.gz");
+    let temp = if tarball {
+        Some(private_temp_dir("adoptiummd-bundle")?)
+    } else {
+        None
+    };
+    let directory = match &temp {
+        Some(temp) => temp.path().to_path_buf(),
+        None => output.to_path_buf(),
+    };
+    create_dir_all(&directory).await?;
+    // Before downloading. So a bundle that can not be verified offline is never created
+    let keyring = bundle_keyring(app, &directory).await?;
+
+    let client = client()?;
+    let retry = &app.adoptium.retry_policy;
+    let mut entries: Vec<BundleEntry> = Vec::new();
+    for settings in settings {
+        let Some(mut release) = app
+            .adoptium
+            .release_information_request(request(settings))
+            .execute()
+            .await?
+            .into_iter()
+            .next()
+        else {
+            return Err(InstallerError::NoReleaseFound(
+                settings.feature_version.to_string(),
+            ));
+        };
+        release.binaries.truncate(1);
+        let Some(binary) = release.binaries.first() else {
+            return Err(InstallerError::NoReleaseFound(release.release_name));
+        };
+        let package = &binary.package;
+        if entries.iter().any(|entry| entry.archive == package.name) {
+            continue;
+        }
+        println!("Downloading {}", package.name);
+        let checksum =
+            download_archive(&client, package, &directory.join(&package.name), retry).await?;
+        tokio::fs::write(
+            directory.join(format!("{}.sha256.txt", package.name)),
+            format!("{checksum}  {}\n", package.name),
+        )
+        .await?;
+        let signature = format!("{}.sig", package.name);
+        let signature =
+            match fetch_signature(&client, retry, package, &directory.join(&signature)).await {
+                Ok(()) => Some(signature),
+                Err(InstallerError::SignatureVerification { reason, .. }) => {
+                    println!("{reason} for {}", package.name);
+                    None
+                }
+                Err(error) => return Err(error),
+            };
+        entries.push(BundleEntry {
+            archive: package.name.clone(),
+            checksum,
+            signature,
+            release,
+        });
+    }
+
+    let manifest = BundleManifest {
+        created: Utc::now(),
+        entries,
+        keyring,
+    };
+    let manifest = serde_json::to_string_pretty(&manifest)
+        .map_err(|err| InstallerError::Custom(err.to_string()))?;
+    tokio::fs::write(directory.join(BUNDLE_MANIFEST), manifest).await?;
+
+    if tarball {
+        let mut builder = Builder::new(GzipEncoder::new(File::create(output).await?));
+        builder.append_dir_all(".", &directory).await?;
+        let mut encoder = builder.into_inner().await?;
+        encoder.shutdown().await?;
+    }
+    println!("Bundle created at {}", output.display());
+    Ok(())
+}
+
+async fn install(
+    mut app: Installer,
+    bundle: &Path,
+    allow_unverified: bool,
+) -> Result<(), InstallerError> {
+    if bundle.is_file() {
+        let directory = private_temp_dir("adoptiummd-bundle-install")?;
+        unpack(bundle, directory.path()).await?;
+        install_entries(&mut app, directory.path(), allow_unverified).await
+    } else {
+        install_entries(&mut app, bundle, allow_unverified).await
+    }
+}
+
+/// Creates a directory only the current user can access. It is removed when dropped
+fn private_temp_dir(prefix: &str) -> Result<TempDir, InstallerError> {
+    Ok(tempfile::Builder::new().prefix(prefix).tempdir()?)
+}
+
+/// Copies the keyring into the bundle so the signatures can be verified on hosts without one.
+///
+/// Fails if signatures are verified and the keyring can not be created
+async fn bundle_keyring(
+    app: &Installer,
+    directory: &Path,
+) -> Result<Option<String>, InstallerError> {
+    let settings = &app.settings.signature;
+    if !settings.enabled {
+        return Ok(None);
+    }
+    SignatureVerifier::new(settings, false)
+        .ensure_keyring()
+        .await?;
+    copy(&settings.keyring, directory.join(BUNDLE_KEYRING)).await?;
+    Ok(Some(BUNDLE_KEYRING.to_string()))
+}
+
+async fn install_entries(
+    app: &mut Installer,
+    directory: &Path,
+    allow_unverified: bool,
+) -> Result<(), InstallerError> {
+    let manifest = directory.join(BUNDLE_MANIFEST);
+    if !manifest.exists() {
+        return Err(InstallerError::InvalidArchive(format!(
+            "{} does not exist",
+            manifest.display()
+        )));
+    }
+    let manifest: BundleManifest =
+        serde_json::from_str(&tokio::fs::read_to_string(manifest).await?)
+            .map_err(|err| InstallerError::InvalidArchive(err.to_string()))?;
+
+    let keyring = manifest.keyring.as_ref().map(|value| directory.join(value));
+    for entry in manifest.entries {
+        let Some(binary) = entry.release.binaries.first() else {
+            continue;
+        };
+        let mut config = InstallConfig::from_release(&entry.release, binary);
+        config.install_location = app.settings.install_location.join(config.to_string());
+        if app.does_install_exist(&config) {
+            println!("{config} is already installed");
+            continue;
+        }
+        let archive = directory.join(&entry.archive);
+        archive::verify_sha256(&archive, &entry.checksum).await?;
+        let signature = entry.signature.as_ref().map(|value| directory.join(value));
+        SignatureVerifier::new(&app.settings.signature, allow_unverified)
+            .with_fallback_keyring(keyring.as_deref())
+            .verify_local(&entry.archive, signature.as_deref(), &archive)
+            .await?;
+
+        println!("Installing {config} from {}", entry.archive);
+        let staging = SysInstaller::staging_directory(&config.install_location);
+        if staging.exists() {
+            remove_dir_all(&staging).await?;
+        }
+        create_dir_all(&staging).await?;
+        unpack(&archive, &staging).await?;
+        register(app, config, staging).await?;
+    }
+    Ok(())
+}
@@ -1,24 +1,26 @@
-use crate::config::InstallSettings;
+use crate::archive::{self, ReleaseFile};
 use crate::download::download;
 use crate::error::InstallerError;
+use crate::signature::SignatureVerifier;
 use crate::sys::SysInstaller;
-use crate::{InstallConfig, Installer, ADOPTIUM_USER_AGENT};
+use crate::{InstallConfig, Installer};
 use adoptium_api::requests::release_information::{
     ReleaseInformationParams, ReleaseInformationParamsBuilder,
 };
+use adoptium_api::requests::release_name::{ReleaseNameParams, ReleaseNameParamsBuilder};
+use adoptium_api::requests::version_range::{
+    VersionRange, VersionRangeParams, VersionRangeParamsBuilder,
+};
 use adoptium_api::requests::AdoptiumRequest;
 
 use adoptium_api::types::{
     AdoptiumJvmImpl, ImageType, ReleaseType, SortMethod, SortOrder, SystemProperties, WithSort,
 };
-use adoptium_api::Adoptium;
 
 use clap::Args;
-use std::env::temp_dir;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::SystemTime;
-
-use url::Url;
+use tokio::fs::{create_dir_all, remove_dir_all};
 
 #[derive(Args)]
 pub struct InstallCommand {
@@ -32,18 +34,33 @@ pub struct InstallCommand {
     /// Defaults to GA
     pub release_type: Option<ReleaseType>,
     #[clap(short, long)]
-    /// A Java Version
-    pub version: i64,
+    /// A Java Version. Defaults to the most recent LTS
+    pub version: Option<i64>,
+    #[clap(long, conflicts_with = "version")]
+    /// A version or a Maven style version range. Such as `[17.0.9,17.1)`
+    pub range: Option<VersionRange>,
+    #[clap(long, conflicts_with_all = ["version", "range"])]
+    /// Installs an exact release. Such as `jdk-21.0.2+13`
+    pub release: Option<String>,
+    #[clap(long)]
+    /// Installs even if the signature of the package can not be verified
+    pub allow_unverified: bool,
+    #[clap(long, conflicts_with_all = ["version", "range", "release"])]
+    /// Installs a local `.tar.gz` archive without contacting the API
+    pub archive: Option<PathBuf>,
+    #[clap(long, requires = "archive")]
+    /// The expected SHA-256 of the archive
+    pub sha256: Option<String>,
 }
 impl InstallCommand {
-    pub fn request(&self) -> ReleaseInformationParams {
+    pub fn request(&self, feature_version: i64) -> ReleaseInformationParams {
         ReleaseInformationParamsBuilder::default()
-            .feature_version(self.version)
-            .release_type(self.release_type.unwrap_or_default())
+            .feature_version(feature_version)
+            .release_type(self.release_type.clone().unwrap_or_default())
             .with_query_builder(|query| {
                 query
-                    .image_type(self.image_type)
-                    .jvm_impl(self.jvm_impl)
+                    .image_type(self.image_type.clone())
+                    .jvm_impl(self.jvm_impl.clone())
                     .local_system(Some(SystemProperties::default()))
                     .with_sort(|sort| {
                         sort.sort_order(SortOrder::Descending)
@@ -55,52 +72,173 @@ impl InstallCommand {
             .build()
             .expect("Failed to build ReleaseInformationParams")
     }
+    pub fn range_request(&self, range: VersionRange) -> VersionRangeParams {
+        VersionRangeParamsBuilder::default()
+            .version(range)
+            .with_query_builder(|query| {
+                query
+                    .image_type(self.image_type.clone())
+                    .jvm_impl(self.jvm_impl.clone())
+                    .release_type(Some(self.release_type.clone().unwrap_or_default()))
+                    .local_system(Some(SystemProperties::default()))
+                    .with_sort(|sort| {
+                        sort.sort_order(SortOrder::Descending)
+                            .sort_method(SortMethod::Default)
+                            .page(0)
+                            .page_size(1);
+                    });
+            })
+            .build()
+            .expect("Failed to build VersionRangeParams")
+    }
+    pub fn release_name_request(&self, release_name: &str) -> ReleaseNameParams {
+        ReleaseNameParamsBuilder::default()
+            .release_name(release_name)
+            .with_query_builder(|query| {
+                query
+                    .image_type(Some(self.image_type.clone().unwrap_or_default()))
+                    .jvm_impl(self.jvm_impl.clone())
+                    .local_system(Some(SystemProperties::default()));
+            })
+            .build()
+            .expect("Failed to build ReleaseNameParams")
+    }
+    /// A human readable description of the requested version
+    fn describe(&self) -> String {
+        match (&self.release, &self.range, self.version) {
+            (Some(release), _, _) => release.clone(),
+            (None, Some(range), _) => range.to_string(),
+            (None, None, Some(version)) => version.to_string(),
+            (None, None, None) => "the most recent LTS".to_string(),
+        }
+    }
 }
 pub async fn execute(mut app: Installer, install: InstallCommand) -> Result<(), InstallerError> {
-    let adoptium = Adoptium::new(ADOPTIUM_USER_AGENT);
-
-    let mut request = adoptium
-        .release_information_request(install.request())
-        .execute()
-        .await?;
-    let mut release = request.remove(0);
-    let binary = release.binaries.remove(0);
-    let download_link = binary.package.link;
-    let size = binary.package.size as u64;
-    let _release_name = release.release_name;
-    let mut config = InstallConfig {
-        install_settings: InstallSettings {
-            heap_size: binary.heap_size,
-            image_type: binary.image_type,
-            jvm_impl: binary.jvm_impl,
-            vendor: release.vendor,
-            project: binary.project,
-            c_lib: None,
-            release_type: release.release_type,
-            feature_version: install.version,
-        },
-        install_location: PathBuf::new(),
-        install_time: SystemTime::now().into(),
-        current_version: release.version_data,
+    if let Some(archive) = &install.archive {
+        return install_archive(&mut app, archive, install.sha256.as_deref()).await;
+    }
+    let releases = if let Some(release_name) = &install.release {
+        let release = app
+            .adoptium
+            .release_name_request(install.release_name_request(release_name))
+            .execute()
+            .await?;
+        vec![release]
+    } else if let Some(range) = install.range.clone() {
+        app.adoptium
+            .version_range_request(install.range_request(range))
+            .execute()
+            .await?
+    } else {
+        let feature_version = match install.version {
+            Some(version) => version,
+            None => {
+                let releases = app.adoptium.available_releases_request().execute().await?;
+                println!(
+                    "No version specified. Using the most recent LTS {}",
+                    releases.most_recent_lts
+                );
+                releases.most_recent_lts
+            }
+        };
+        app.adoptium
+            .release_information_request(install.request(feature_version))
+            .execute()
+            .await?
+    };
+    let Some(release) = releases.into_iter().next() else {
+        return Err(InstallerError::NoReleaseFound(install.describe()));
+    };
+    let Some(binary) = release.binaries.first() else {
+        return Err(InstallerError::NoReleaseFound(install.describe()));
     };
+    let mut config = InstallConfig::from_release(&release, binary);
+    config.install_location = app.settings.install_location.join(config.to_string());
     if app.does_install_exist(&config) {
         println!("Install Already Exists");
         return Ok(());
     }
-    config.install_location = app.settings.install_location.join(config.to_string());
 
-    let temp_file = temp_dir().join(config.to_string());
-    download(
-        Url::try_from(download_link.as_ref()).unwrap(),
-        size,
-        temp_file.clone(),
+    let verifier = SignatureVerifier::new(&app.settings.signature, install.allow_unverified);
+    let staging = download(
+        &binary.package,
+        &config.install_location,
+        &app.adoptium.retry_policy,
+        &verifier,
     )
     .await?;
-    let mut installer = SysInstaller::new(&config, temp_file);
-    installer.find_internal_data().await?;
-    installer.move_data().await?;
-    installer.update_system(&app.settings.system).await?;
-    drop(installer);
-    app.add_install(config).await?;
-    Ok(())
+    register(&mut app, config, staging).await
+}
+
+/// Swaps the extracted data into the install location and saves its config.
+///
+/// The previous state is restored if any step fails
+pub(crate) async fn register(
+    app: &mut Installer,
+    config: InstallConfig,
+    staging: PathBuf,
+) -> Result<(), InstallerError> {
+    let mut installer = SysInstaller::new(&config, staging);
+    let result = async {
+        installer.find_internal_data().await?;
+        installer.swap_in().await?;
+        installer.update_system(&app.settings.system).await?;
+        app.add_install(config.clone()).await
+    }
+    .await;
+    match result {
+        Ok(()) => installer.commit(None).await,
+        Err(error) => {
+            let rollback = installer.rollback(&app.settings.system).await;
+            Err(error.with_rollback(rollback))
+        }
+    }
+}
+
+async fn install_archive(
+    app: &mut Installer,
+    archive: &Path,
+    sha256: Option<&str>,
+) -> Result<(), InstallerError> {
+    let name = archive
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| InstallerError::InvalidArchive(archive.display().to_string()))?;
+    if let Some(expected) = sha256 {
+        archive::verify_sha256(archive, expected).await?;
+    }
+    let extracted = SysInstaller::staging_directory(&app.settings.install_location.join(&name));
+    if extracted.exists() {
+        remove_dir_all(&extracted).await?;
+    }
+    create_dir_all(&extracted).await?;
+    let result = async {
+        archive::unpack(archive, &extracted).await?;
+        let release = ReleaseFile::read(&archive::find_java_home(&extracted).await?).await?;
+        let version = release.version_data()?;
+        Ok::<_, InstallerError>(InstallConfig {
+            install_settings: release.install_settings(&version),
+            install_location: PathBuf::new(),
+            install_time: SystemTime::now().into(),
+            release_date: release.release_date(),
+            current_version: version,
+            retained: Vec::new(),
+        })
+    }
+    .await;
+    let mut config = match result {
+        Ok(config) => config,
+        Err(error) => {
+            remove_dir_all(&extracted).await?;
+            return Err(error);
+        }
+    };
+    if app.does_install_exist(&config) {
+        remove_dir_all(&extracted).await?;
+        println!("Install Already Exists");
+        return Ok(());
+    }
+    config.install_location = app.settings.install_location.join(config.to_string());
+    println!("Installing {config} from {}", archive.display());
+    register(app, config, extracted).await
 }
@@ -1,5 +1,5 @@
 use crate::error::InstallerError;
-use crate::utils::get_latest_version;
+use crate::utils::get_newer_version;
 use crate::{InstallTable, Installer, UpToDate};
 use clap::Args;
 use tabled::settings::Style;
@@ -12,11 +12,26 @@ pub struct ListCommand {}
 pub async fn execute(app: Installer, _install: ListCommand) -> Result<(), InstallerError> {
     let mut versions = Vec::new();
     for install in app.installs.iter() {
-        let datum = get_latest_version(&install.config.install_settings).await?;
-        let up_to_date = if datum.version_data > install.config.current_version {
-            UpToDate::No(datum.version_data.semver)
-        } else {
-            UpToDate::Yes
+        let config = &install.config;
+        let up_to_date = match get_newer_version(
+            &app.adoptium,
+            &config.install_settings,
+            &config.current_version,
+        )
+        .await
+        {
+            Ok(Some((datum, released_on))) => UpToDate::No {
+                latest: datum.version.semver,
+                released_on,
+            },
+            Ok(None) => UpToDate::Yes,
+            Err(error) => {
+                warn!(
+                    "Unable to find the latest version of {}: {error}",
+                    install.config
+                );
+                UpToDate::Unknown
+            }
         };
         let location = install
             .config
@@ -29,10 +44,12 @@ pub async fn execute(app: Installer, _install: ListCommand) -> Result<(), Instal
             });
         versions.push(InstallTable {
             version: &install.config.current_version.semver,
+            released_on: install.config.human_release_date(),
             location,
             installed_on: install.config.human_date_time().to_string(),
             id: install.config.to_string(),
             up_to_date,
+            default: app.is_default(&install.config),
         })
     }
     println!("{}", Table::new(&versions).with(Style::ascii()));
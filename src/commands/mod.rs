@@ -3,10 +3,14 @@ use clap::Args;
 
 use crate::Installer;
 
+pub mod bundle;
 pub mod install;
 pub mod list;
+pub mod rollback;
+pub mod stats;
 pub mod uninstall;
 pub mod update;
+pub mod use_install;
 pub trait Command: Args {
     async fn execute(self, app: Installer) -> Result<(), AdoptiumError>;
 }
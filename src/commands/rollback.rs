@@ -0,0 +1,109 @@
+use crate::error::InstallerError;
+use crate::sys::SysInstaller;
+use crate::Installer;
+use adoptium_api::response::VersionData;
+use clap::Args;
+use std::str::FromStr;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+#[derive(Args)]
+pub struct RollbackCommand {
+    /// The install. Such as `21-jdk-normal-hotspot`
+    pub id: String,
+    #[clap(long)]
+    /// A retained version. Such as `21.0.1+12`. Defaults to the most recent
+    pub to: Option<String>,
+    #[clap(short, long, conflicts_with = "to")]
+    /// Lists the retained versions
+    pub list: bool,
+}
+
+#[derive(Tabled)]
+struct RetainedTable<'a> {
+    version: &'a str,
+    released_on: String,
+    installed_on: String,
+    location: String,
+}
+
+pub async fn execute(mut app: Installer, command: RollbackCommand) -> Result<(), InstallerError> {
+    let Some(install) = app
+        .installs
+        .iter_mut()
+        .find(|value| value.config.eq(&command.id))
+    else {
+        println!("Installation by that name not found");
+        return Ok(());
+    };
+    if command.list {
+        let rows = install
+            .config
+            .retained
+            .iter()
+            .map(|retained| RetainedTable {
+                version: &retained.version.semver,
+                released_on: retained
+                    .release_date
+                    .map(|date| date.format("%Y-%m-%d").to_string())
+                    .unwrap_or_else(|| "Unknown".to_string()),
+                installed_on: retained.install_time.format("%Y-%m-%d %H:%M").to_string(),
+                location: retained.location.display().to_string(),
+            })
+            .collect::<Vec<_>>();
+        println!("{}", Table::new(&rows).with(Style::ascii()));
+        return Ok(());
+    }
+
+    let index = match &command.to {
+        Some(to) => {
+            let version = VersionData::from_str(to)?;
+            install
+                .config
+                .retained
+                .iter()
+                .position(|retained| retained.version == version)
+        }
+        None => (!install.config.retained.is_empty()).then_some(0),
+    };
+    let Some(index) = index else {
+        return Err(InstallerError::NoReleaseFound(format!(
+            "{} in the retained versions of {}",
+            command.to.as_deref().unwrap_or("any version"),
+            install.config
+        )));
+    };
+
+    let mut config = install.config.clone();
+    let target = config.retained.remove(index);
+    if !target.location.exists() {
+        return Err(InstallerError::Custom(format!(
+            "{} no longer exists",
+            target.location.display()
+        )));
+    }
+    let retain = SysInstaller::retained_directory(
+        &install.config.install_location,
+        &install.config.current_version,
+    );
+    config
+        .retained
+        .insert(0, install.config.retained_version(retain.clone()));
+    config.current_version = target.version;
+    config.release_date = target.release_date;
+    config.install_time = target.install_time;
+
+    println!(
+        "Rolling back {} from {} to {}",
+        install.config, install.config.current_version.semver, config.current_version.semver
+    );
+    install
+        .replace(
+            config,
+            target.location,
+            true,
+            &app.settings.system,
+            Some(&retain),
+        )
+        .await
+}
@@ -0,0 +1,170 @@
+use crate::error::InstallerError;
+use crate::Installer;
+use adoptium_api::requests::stats::{MonthlyQueryParamsBuilder, TrackingQueryParamsBuilder};
+use adoptium_api::requests::AdoptiumRequest;
+use adoptium_api::types::{AdoptiumJvmImpl, StatsSource};
+use clap::{Args, Subcommand};
+use std::collections::BTreeMap;
+use tabled::settings::Style;
+use tabled::{Table, Tabled};
+
+#[derive(Args)]
+pub struct StatsCommand {
+    #[clap(subcommand)]
+    pub stats: Stats,
+}
+
+#[derive(Subcommand)]
+pub enum Stats {
+    /// Total downloads of every feature version
+    Total,
+    /// Downloads of each release of a feature version
+    Version {
+        /// A Java Version
+        version: i64,
+        #[clap(short, long)]
+        /// Downloads of each asset of a release. Such as `jdk-21.0.2+13`
+        release: Option<String>,
+    },
+    /// Daily downloads
+    Tracking {
+        #[clap(short, long)]
+        /// Defaults to 30
+        days: Option<i64>,
+        #[clap(short, long)]
+        version: Option<i64>,
+        #[clap(short, long)]
+        source: Option<StatsSource>,
+        #[clap(short, long)]
+        jvm_impl: Option<AdoptiumJvmImpl>,
+    },
+    /// Monthly downloads
+    Monthly {
+        #[clap(short, long)]
+        version: Option<i64>,
+        #[clap(short, long)]
+        source: Option<StatsSource>,
+        #[clap(short, long)]
+        jvm_impl: Option<AdoptiumJvmImpl>,
+    },
+}
+
+#[derive(Tabled)]
+struct DownloadsTable {
+    name: String,
+    downloads: i64,
+}
+
+#[derive(Tabled)]
+struct PeriodTable {
+    period: String,
+    downloads: i64,
+    total: i64,
+}
+
+fn print_downloads<K: ToString>(downloads: BTreeMap<K, i64>) {
+    let rows = downloads
+        .into_iter()
+        .map(|(name, downloads)| DownloadsTable {
+            name: name.to_string(),
+            downloads,
+        })
+        .collect::<Vec<_>>();
+    println!("{}", Table::new(&rows).with(Style::ascii()));
+}
+
+pub async fn execute(app: Installer, stats: StatsCommand) -> Result<(), InstallerError> {
+    match stats.stats {
+        Stats::Total => {
+            let total = app.adoptium.total_downloads_request().execute().await?;
+            println!("GitHub Downloads");
+            print_downloads(total.github_downloads);
+            println!("Docker Pulls");
+            print_downloads(total.docker_pulls);
+            print_downloads(BTreeMap::from([
+                (
+                    "GitHub Downloads".to_string(),
+                    total.total_downloads.github_downloads,
+                ),
+                (
+                    "Docker Pulls".to_string(),
+                    total.total_downloads.docker_pulls,
+                ),
+                ("Total".to_string(), total.total_downloads.total),
+            ]));
+        }
+        Stats::Version {
+            version,
+            release: None,
+        } => {
+            let downloads = app
+                .adoptium
+                .feature_version_downloads_request(version)
+                .execute()
+                .await?;
+            print_downloads(downloads);
+        }
+        Stats::Version {
+            version,
+            release: Some(release),
+        } => {
+            let downloads = app
+                .adoptium
+                .release_downloads_request(version, release)
+                .execute()
+                .await?;
+            print_downloads(downloads);
+        }
+        Stats::Tracking {
+            days,
+            version,
+            source,
+            jvm_impl,
+        } => {
+            let params = TrackingQueryParamsBuilder::default()
+                .days(days)
+                .feature_version(version)
+                .source(source)
+                .jvm_impl(jvm_impl)
+                .build();
+            let rows = app
+                .adoptium
+                .download_tracking_request(params)
+                .execute()
+                .await?
+                .into_iter()
+                .map(|day| PeriodTable {
+                    period: day.date.format("%Y-%m-%d").to_string(),
+                    downloads: day.daily,
+                    total: day.total,
+                })
+                .collect::<Vec<_>>();
+            println!("{}", Table::new(&rows).with(Style::ascii()));
+        }
+        Stats::Monthly {
+            version,
+            source,
+            jvm_impl,
+        } => {
+            let params = MonthlyQueryParamsBuilder::default()
+                .feature_version(version)
+                .source(source)
+                .jvm_impl(jvm_impl)
+                .build();
+            let rows = app
+                .adoptium
+                .monthly_downloads_request(params)
+                .execute()
+                .await?
+                .into_iter()
+                .map(|month| PeriodTable {
+                    period: month.month,
+                    downloads: month.monthly,
+                    total: month.total,
+                })
+                .collect::<Vec<_>>();
+            println!("{}", Table::new(&rows).with(Style::ascii()));
+        }
+    }
+    Ok(())
+}
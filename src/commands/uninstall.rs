@@ -1,3 +1,4 @@
+use crate::config::save_settings;
 use crate::error::InstallerError;
 use crate::sys::SysInstaller;
 use crate::Installer;
@@ -21,11 +22,26 @@ pub async fn execute(mut app: Installer, install: UninstallCommand) -> Result<()
         }
         Some(value) => {
             println!("Uninstalling");
-            SysInstaller::remove_install(&app.settings.system, &value).await?;
+            SysInstaller::remove_install(&app.settings.system, value).await?;
             remove_dir_all(&value.config.install_location).await?;
+            for retained in &value.config.retained {
+                if retained.location.exists() {
+                    remove_dir_all(&retained.location).await?;
+                }
+            }
 
             println!("Removing Config");
             remove_file(&value.install_file).await?;
+            if app
+                .settings
+                .default_install
+                .as_ref()
+                .is_some_and(|id| value.config.eq(id))
+            {
+                app.settings.default_install = None;
+                app.settings.default_version = None;
+                save_settings(&app.settings).await?;
+            }
         }
     }
 
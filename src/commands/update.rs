@@ -1,16 +1,21 @@
-use std::env::temp_dir;
 use std::time::SystemTime;
 
+use crate::config::Settings;
 use crate::download::download;
 use crate::error::InstallerError;
+use crate::signature::SignatureVerifier;
 use crate::sys::SysInstaller;
-use crate::utils::get_latest_version;
+use crate::utils::{
+    get_latest_version, get_newer_version, get_release_date, get_release_notes_between,
+};
 use crate::{Install, InstallTable, Installer, UpToDate};
+use adoptium_api::requests::release_notes::{ReleaseNote, ReleaseNotes};
+use adoptium_api::Adoptium;
 use clap::Args;
 
 use tabled::settings::Style;
-use tabled::Table;
-use url::Url;
+use tabled::{Table, Tabled};
+use tracing::warn;
 
 #[derive(Args)]
 pub struct UpdateCommand {
@@ -18,6 +23,55 @@ pub struct UpdateCommand {
     pub list: bool,
     #[clap(short, long, required_unless_present = "list")]
     pub update: Option<String>,
+    #[clap(short, long)]
+    /// Shows the issues fixed since the installed version
+    pub changelog: bool,
+    #[clap(short, long, requires = "changelog")]
+    /// Only shows issues of this priority or higher. `1` is the highest
+    pub priority: Option<u8>,
+    #[clap(long)]
+    /// Updates even if the signature of the package can not be verified
+    pub allow_unverified: bool,
+}
+
+#[derive(Tabled)]
+struct ReleaseNoteTable<'a> {
+    id: &'a str,
+    priority: &'a str,
+    #[tabled(rename = "type")]
+    issue_type: &'a str,
+    component: &'a str,
+    title: &'a str,
+}
+
+impl<'a> From<&'a ReleaseNote> for ReleaseNoteTable<'a> {
+    fn from(note: &'a ReleaseNote) -> Self {
+        ReleaseNoteTable {
+            id: &note.id,
+            priority: note.priority.as_deref().unwrap_or_default(),
+            issue_type: note.issue_type.as_deref().unwrap_or_default(),
+            component: note.component.as_deref().unwrap_or_default(),
+            title: &note.title,
+        }
+    }
+}
+
+fn print_changelog(releases: &[ReleaseNotes], priority: Option<u8>) {
+    for release in releases {
+        let rows = release
+            .release_notes
+            .iter()
+            .filter(|note| match priority {
+                Some(max) => note.priority_level().is_some_and(|level| level <= max),
+                None => true,
+            })
+            .map(ReleaseNoteTable::from)
+            .collect::<Vec<_>>();
+        println!("{} ({} issues)", release.release_name, rows.len());
+        if !rows.is_empty() {
+            println!("{}", Table::new(&rows).with(Style::ascii()));
+        }
+    }
 }
 
 pub async fn execute(app: Installer, value: UpdateCommand) -> Result<(), InstallerError> {
@@ -31,17 +85,45 @@ pub async fn execute(app: Installer, value: UpdateCommand) -> Result<(), Install
     }
 }
 
-async fn list_updates(app: Installer, _install: UpdateCommand) -> Result<(), InstallerError> {
+async fn list_updates(app: Installer, command: UpdateCommand) -> Result<(), InstallerError> {
     let mut versions = Vec::new();
+    let mut changelogs = Vec::new();
     for install in app.installs.iter() {
-        let datum = get_latest_version(&install.config.install_settings).await?;
-        let up_to_date = if datum.version_data > install.config.current_version {
-            UpToDate::No(datum.version_data.semver)
-        } else {
-            continue;
+        let up_to_date = match get_newer_version(
+            &app.adoptium,
+            &install.config.install_settings,
+            &install.config.current_version,
+        )
+        .await
+        {
+            Ok(Some((datum, released_on))) => {
+                if command.changelog {
+                    let notes = get_release_notes_between(
+                        &app.adoptium,
+                        &install.config.install_settings,
+                        &install.config.current_version,
+                        &datum.version,
+                    )
+                    .await?;
+                    changelogs.push((install.config.to_string(), notes));
+                }
+                UpToDate::No {
+                    latest: datum.version.semver,
+                    released_on,
+                }
+            }
+            Ok(None) => continue,
+            Err(error) => {
+                warn!(
+                    "Unable to find the latest version of {}: {error}",
+                    install.config
+                );
+                UpToDate::Unknown
+            }
         };
         versions.push(InstallTable {
             version: &install.config.current_version.semver,
+            released_on: install.config.human_release_date(),
             location: install
                 .config
                 .install_location
@@ -51,17 +133,27 @@ async fn list_updates(app: Installer, _install: UpdateCommand) -> Result<(), Ins
             installed_on: install.config.human_date_time().to_string(),
             id: install.config.to_string(),
             up_to_date,
+            default: app.is_default(&install.config),
         })
     }
     println!("{}", Table::new(&versions).with(Style::ascii()));
+    for (id, notes) in changelogs {
+        println!("Changes for {id}");
+        print_changelog(&notes, command.priority);
+    }
     Ok(())
 }
 
 async fn update(mut app: Installer, install: UpdateCommand) -> Result<(), InstallerError> {
-    let value = install.update.unwrap();
+    let value = install.update.clone().unwrap();
     if value.eq("all") {
-        for install in app.installs.iter_mut() {
-            update_internal(install).await?;
+        for value in app.installs.iter_mut() {
+            match update_internal(&app.adoptium, &app.settings, value, &install).await {
+                Err(error @ InstallerError::NotUpdatable(_)) => {
+                    println!("Skipping {}. {error}", value.config);
+                }
+                result => result?,
+            }
         }
     } else {
         match app.installs.iter_mut().find(|v| v.config.eq(&value)) {
@@ -69,43 +161,71 @@ async fn update(mut app: Installer, install: UpdateCommand) -> Result<(), Instal
                 println!("Installation by that name not found")
             }
             Some(value) => {
-                update_internal(value).await?;
+                update_internal(&app.adoptium, &app.settings, value, &install).await?;
             }
         }
     }
     Ok(())
 }
 
-async fn update_internal(install: &mut Install) -> Result<(), InstallerError> {
-    let datum = get_latest_version(&install.config.install_settings).await?;
-    if datum.version_data <= install.config.current_version {
+async fn update_internal(
+    adoptium: &Adoptium,
+    settings: &Settings,
+    install: &mut Install,
+    command: &UpdateCommand,
+) -> Result<(), InstallerError> {
+    let datum = get_latest_version(adoptium, &install.config.install_settings).await?;
+    if datum.version <= install.config.current_version {
         println!(
             "{} is already on the latest version {}",
-            &install.config, &datum.version_data.semver
+            &install.config, &datum.version.semver
         );
         return Ok(());
     } else {
         println!(
             "Updating {} to version {}",
-            &install.config, &datum.version_data.semver
+            &install.config, &datum.version.semver
         );
     }
+    if command.changelog {
+        let notes = get_release_notes_between(
+            adoptium,
+            &install.config.install_settings,
+            &install.config.current_version,
+            &datum.version,
+        )
+        .await?;
+        print_changelog(&notes, command.priority);
+    }
 
-    install.config.current_version = datum.version_data;
-    install.config.install_time = SystemTime::now().into();
+    let mut config = install.config.clone();
+    let retain = (settings.retained_versions > 0).then(|| {
+        SysInstaller::retained_directory(
+            &install.config.install_location,
+            &install.config.current_version,
+        )
+    });
+    if let Some(location) = &retain {
+        config
+            .retained
+            .insert(0, install.config.retained_version(location.clone()));
+    }
+    config.release_date = Some(get_release_date(adoptium, &datum).await?);
+    config.current_version = datum.version;
+    config.install_time = SystemTime::now().into();
+    let pruned = config.prune_retained(settings.retained_versions);
 
-    let temp_file = temp_dir().join(install.config.to_string());
-    let binary = datum.binaries.first().unwrap();
-    download(
-        Url::try_from(binary.package.link.as_ref()).unwrap(),
-        binary.package.size as u64,
-        temp_file.clone(),
+    let verifier = SignatureVerifier::new(&settings.signature, command.allow_unverified);
+    let staging = download(
+        &datum.binary.package,
+        &config.install_location,
+        &adoptium.retry_policy,
+        &verifier,
     )
     .await?;
     println!("Download Complete. Moving Files");
-    let mut installer = SysInstaller::new(&install.config, temp_file);
-    installer.find_internal_data().await?;
-    installer.move_data().await?;
-    install.update().await?;
-    Ok(())
+    install
+        .replace(config, staging, false, &settings.system, retain.as_deref())
+        .await?;
+    SysInstaller::remove_pruned(&pruned, &install.config.retained).await
 }
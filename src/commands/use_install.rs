@@ -0,0 +1,31 @@
+use crate::config::save_settings;
+use crate::error::InstallerError;
+use crate::sys::SysInstaller;
+use crate::Installer;
+use clap::Args;
+
+#[derive(Args)]
+pub struct UseCommand {
+    /// The install. Such as `21-jdk-normal-hotspot`
+    pub id: String,
+}
+
+pub async fn execute(mut app: Installer, command: UseCommand) -> Result<(), InstallerError> {
+    let Some(install) = app
+        .installs
+        .iter()
+        .find(|value| value.config.eq(&command.id))
+    else {
+        println!("Installation by that name not found");
+        return Ok(());
+    };
+    SysInstaller::set_default(&app.settings.system, &install.config).await?;
+    app.settings.default_install = Some(install.config.to_string());
+    app.settings.default_version = Some(install.config.current_version.major);
+    save_settings(&app.settings).await?;
+    println!(
+        "{} ({}) is now the default",
+        install.config, install.config.current_version.semver
+    );
+    Ok(())
+}
@@ -1,19 +1,25 @@
 use crate::error::InstallerError;
+use crate::signature::ADOPTIUM_KEY_FINGERPRINT;
 use crate::sys::SysConfig;
+use crate::ADOPTIUM_USER_AGENT;
+use adoptium_api::requests::release_information::{Binary, ReleaseInformationDatum};
 use adoptium_api::response::VersionData;
 use adoptium_api::types::{
     AdoptiumJvmImpl, CLib, HeapSize, ImageType, Project, ReleaseType, Vendor,
 };
+use adoptium_api::{Adoptium, ResponseCache, RetryPolicy, ADOPTIUM_API_URL};
 use chrono::format::{DelayedFormat, StrftimeItems};
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
 
 use std::fmt::{Display, Formatter};
 use std::path::PathBuf;
+use std::time::{Duration, SystemTime};
 use tokio::fs::{create_dir_all, OpenOptions};
 use tokio::io::AsyncWriteExt;
 use tokio_stream::wrappers::ReadDirStream;
 use tokio_stream::StreamExt;
+use url::Url;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct InstallSettings {
@@ -27,17 +33,76 @@ pub struct InstallSettings {
     pub feature_version: i64,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct InstallConfig {
     pub install_location: PathBuf,
     pub install_time: DateTime<Local>,
     pub install_settings: InstallSettings,
     pub current_version: VersionData,
+    /// When the installed build was released
+    #[serde(default)]
+    pub release_date: Option<DateTime<Utc>>,
+    /// Previous versions kept by `update`. The most recent first
+    #[serde(default)]
+    pub retained: Vec<RetainedVersion>,
+}
+
+/// A previous version of an install that can be rolled back to
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RetainedVersion {
+    pub location: PathBuf,
+    pub version: VersionData,
+    #[serde(default)]
+    pub release_date: Option<DateTime<Utc>>,
+    pub install_time: DateTime<Local>,
 }
 impl InstallConfig {
+    /// A new install of a binary of the release. The install location is set when it is installed
+    pub fn from_release(release: &ReleaseInformationDatum, binary: &Binary) -> InstallConfig {
+        InstallConfig {
+            install_settings: InstallSettings {
+                heap_size: binary.heap_size.clone(),
+                image_type: binary.image_type.clone(),
+                jvm_impl: binary.jvm_impl.clone(),
+                vendor: release.vendor.clone(),
+                project: binary.project.clone(),
+                c_lib: None,
+                release_type: release.release_type.clone(),
+                feature_version: release.version_data.major,
+            },
+            install_location: PathBuf::new(),
+            install_time: SystemTime::now().into(),
+            current_version: release.version_data.clone(),
+            release_date: Some(release.timestamp),
+            retained: Vec::new(),
+        }
+    }
+    /// The current version as a [RetainedVersion] kept at `location`
+    pub fn retained_version(&self, location: PathBuf) -> RetainedVersion {
+        RetainedVersion {
+            location,
+            version: self.current_version.clone(),
+            release_date: self.release_date,
+            install_time: self.install_time,
+        }
+    }
+    /// Keeps the `keep` most recent retained versions other than the current version. Returns the others
+    pub fn prune_retained(&mut self, keep: usize) -> Vec<RetainedVersion> {
+        let (mut retained, mut pruned): (Vec<_>, Vec<_>) = std::mem::take(&mut self.retained)
+            .into_iter()
+            .partition(|retained| retained.version != self.current_version);
+        pruned.extend(retained.split_off(retained.len().min(keep)));
+        self.retained = retained;
+        pruned
+    }
     pub fn human_date_time(&self) -> DelayedFormat<StrftimeItems<'_>> {
         self.install_time.format("%Y-%m-%d %H:%M")
     }
+    pub fn human_release_date(&self) -> String {
+        self.release_date
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_else(|| "Unknown".to_string())
+    }
 }
 
 impl Display for InstallConfig {
@@ -62,8 +127,124 @@ impl PartialEq<String> for InstallConfig {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Settings {
     pub install_location: PathBuf,
+    /// The Java version of the default install
     pub default_version: Option<i64>,
+    /// The install selected with `use`
+    #[serde(default)]
+    pub default_install: Option<String>,
     pub system: SysConfig,
+    /// The base URL of the Adoptium API
+    #[serde(default = "default_api_url")]
+    pub api_url: Url,
+    /// How failed API requests and downloads are retried
+    #[serde(default)]
+    pub retry: RetryPolicy,
+    #[serde(default)]
+    pub cache: CacheSettings,
+    #[serde(default)]
+    pub signature: SignatureSettings,
+    /// How many previous versions `update` keeps for `rollback`
+    #[serde(default = "default_retained_versions")]
+    pub retained_versions: usize,
+}
+
+/// Verification of the detached GPG signatures of packages
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct SignatureSettings {
+    pub enabled: bool,
+    /// The keyring passed to `gpgv`
+    pub keyring: PathBuf,
+    /// Fingerprint of the key received from `keyserver`
+    pub default_key: Option<String>,
+    /// Receives `default_key` from this keyserver when the keyring does not exist.
+    /// Otherwise the Adoptium key embedded in the binary is used
+    pub keyserver: Option<String>,
+}
+
+impl Default for SignatureSettings {
+    fn default() -> Self {
+        SignatureSettings {
+            enabled: true,
+            keyring: PathBuf::from("/etc").join("adoptium").join("adoptium.gpg"),
+            default_key: Some(ADOPTIUM_KEY_FINGERPRINT.to_string()),
+            keyserver: None,
+        }
+    }
+}
+
+/// The on disk cache for API responses
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct CacheSettings {
+    pub enabled: bool,
+    pub directory: PathBuf,
+    /// How long a response is used before it is revalidated
+    pub ttl_seconds: u64,
+    /// Use expired responses if the API can not be reached
+    pub serve_stale: bool,
+}
+
+impl Default for CacheSettings {
+    fn default() -> Self {
+        CacheSettings {
+            enabled: true,
+            directory: PathBuf::from("/var").join("cache").join("adoptiummd"),
+            ttl_seconds: 60 * 60,
+            serve_stale: true,
+        }
+    }
+}
+
+impl CacheSettings {
+    pub fn response_cache(&self) -> Option<ResponseCache> {
+        if !self.enabled {
+            return None;
+        }
+        Some(ResponseCache {
+            directory: self.directory.clone(),
+            ttl: Duration::from_secs(self.ttl_seconds),
+            serve_stale: self.serve_stale,
+        })
+    }
+}
+
+fn default_retained_versions() -> usize {
+    1
+}
+
+fn default_api_url() -> Url {
+    Url::parse(ADOPTIUM_API_URL).expect("ADOPTIUM_API_URL is a valid URL")
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Settings {
+            install_location: PathBuf::from("/").join("usr").join("lib").join("jvm"),
+            default_version: None,
+            default_install: None,
+            system: Default::default(),
+            api_url: default_api_url(),
+            retry: RetryPolicy::default(),
+            cache: CacheSettings::default(),
+            signature: SignatureSettings::default(),
+            retained_versions: default_retained_versions(),
+        }
+    }
+}
+
+impl Settings {
+    pub fn adoptium(&self) -> Result<Adoptium, InstallerError> {
+        let mut builder = Adoptium::builder();
+        builder
+            .base_url(self.api_url.clone())
+            .user_agent(ADOPTIUM_USER_AGENT)
+            .retry_policy(self.retry.clone());
+        if let Some(cache) = self.cache.response_cache() {
+            builder.cache(cache);
+        }
+        builder.build().map_err(InstallerError::from)
+    }
 }
 
 pub fn get_config_directory() -> PathBuf {
@@ -122,7 +303,8 @@ pub async fn save_settings(settings: &Settings) -> Result<(), InstallerError> {
     }
     let config = config.join("adoptium.toml");
     let mut file = OpenOptions::new()
-        .create_new(true)
+        .create(true)
+        .truncate(true)
         .write(true)
         .open(config)
         .await?;
@@ -130,3 +312,65 @@ pub async fn save_settings(settings: &Settings) -> Result<(), InstallerError> {
     file.write_all(string.as_bytes()).await?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INSTALL: &str = r#"
+install_location = "/usr/lib/jvm/21-jdk-normal-hotspot"
+install_time = "2024-02-01T10:00:00+01:00"
+release_date = "2024-01-16T16:08:24Z"
+
+[install_settings]
+heap_size = "normal"
+image_type = "jdk"
+jvm_impl = "hotspot"
+vendor = "eclipse"
+project = "jdk"
+release_type = "ga"
+feature_version = 21
+
+[current_version]
+major = 21
+build = 13
+minor = 0
+security = 2
+semver = "21.0.2+13"
+openjdk_version = "21.0.2+13-LTS"
+
+[[retained]]
+location = "/usr/lib/jvm/.21-jdk-normal-hotspot.21.0.1+12"
+install_time = "2023-11-01T10:00:00+01:00"
+release_date = "2023-10-17T18:53:01Z"
+
+[retained.version]
+major = 21
+build = 12
+minor = 0
+security = 1
+semver = "21.0.1+12"
+openjdk_version = "21.0.1+12-LTS"
+"#;
+
+    #[test]
+    pub fn release_dates() {
+        let config: InstallConfig = toml::from_str(INSTALL).unwrap();
+        assert_eq!(config.human_release_date(), "2024-01-16");
+        assert_eq!(
+            config.release_date,
+            Some("2024-01-16T16:08:24Z".parse().unwrap())
+        );
+        assert_eq!(
+            config.retained[0].release_date,
+            Some("2023-10-17T18:53:01Z".parse().unwrap())
+        );
+
+        // Configs written before release dates were stored
+        let config: InstallConfig =
+            toml::from_str(&INSTALL.replace("release_date = ", "# release_date = ")).unwrap();
+        assert_eq!(config.release_date, None);
+        assert_eq!(config.retained[0].release_date, None);
+        assert_eq!(config.human_release_date(), "Unknown");
+    }
+}
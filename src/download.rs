@@ -1,50 +1,191 @@
+use crate::archive::unpack;
+use crate::signature::SignatureVerifier;
+use crate::sys::SysInstaller;
 use crate::{InstallerError, ADOPTIUM_USER_AGENT};
+use adoptium_api::error::{AdoptiumError, IntoResult};
+use adoptium_api::response::Package;
+use adoptium_api::RetryPolicy;
 
-use async_compression::tokio::bufread::GzipDecoder;
 use bytes::Bytes;
 use futures_util::StreamExt;
 use indicatif::{ProgressBar, ProgressStyle};
-use reqwest::ClientBuilder;
+use reqwest::{Client, ClientBuilder};
+use sha2::{Digest, Sha256};
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use tokio::fs::{create_dir_all, remove_dir_all};
-use tokio::io::{duplex, AsyncWriteExt, BufReader};
+use tokio::fs::{create_dir_all, remove_dir_all, File};
+use tokio::io::AsyncWriteExt;
 
-use tokio_tar::Archive;
+use tracing::warn;
 use url::Url;
 
-pub async fn download(url: Url, total_size: u64, location: PathBuf) -> Result<(), InstallerError> {
-    let client = ClientBuilder::new()
-        .user_agent(ADOPTIUM_USER_AGENT)
-        .build()?;
-    if location.exists() {
-        remove_dir_all(&location).await?
+/// Downloads the package and unpacks it into the staging directory of the install. Returns the staging directory.
+///
+/// The archive is downloaded into [SysInstaller::download_directory] and only unpacked after its SHA-256 and signature are verified
+pub async fn download(
+    package: &Package,
+    install_location: &Path,
+    retry: &RetryPolicy,
+    verifier: &SignatureVerifier<'_>,
+) -> Result<PathBuf, InstallerError> {
+    let client = client()?;
+    let location = SysInstaller::staging_directory(install_location);
+    let downloads = SysInstaller::download_directory(install_location);
+    for directory in [&location, &downloads] {
+        if directory.exists() {
+            remove_dir_all(directory).await?
+        }
+        create_dir_all(directory).await?;
+    }
+    let archive = downloads.join(&package.name);
+    let result = async {
+        download_archive(&client, package, &archive, retry).await?;
+        verifier.verify(&client, retry, package, &archive).await?;
+        unpack(&archive, &location).await
+    }
+    .await;
+    let cleanup = remove_dir_all(&downloads).await;
+    if let Err(error) = result {
+        // The cause of the failure is reported instead of a failed cleanup
+        if let Err(cleanup) = cleanup {
+            warn!(%cleanup, "Unable to remove {}", downloads.display());
+        }
+        if let Err(cleanup) = remove_dir_all(&location).await {
+            warn!(%cleanup, "Unable to remove {}", location.display());
+        }
+        return Err(error);
     }
-    create_dir_all(&location).await?;
-    let pb = ProgressBar::new(total_size);
+    cleanup?;
+    Ok(location)
+}
+
+pub fn client() -> Result<Client, InstallerError> {
+    Ok(ClientBuilder::new()
+        .user_agent(ADOPTIUM_USER_AGENT)
+        .build()?)
+}
+
+/// Streams the archive to disk while computing its SHA-256.
+///
+/// Returns the verified checksum
+pub async fn download_archive(
+    client: &Client,
+    package: &Package,
+    archive: &Path,
+    retry: &RetryPolicy,
+) -> Result<String, InstallerError> {
+    let expected = expected_checksum(client, package, retry).await?;
+    let url = Url::parse(&package.link)
+        .map_err(|err| InstallerError::Custom(format!("Invalid download link {err}")))?;
+    let pb = ProgressBar::new(package.size as u64);
     pb.set_style(ProgressStyle::default_bar()
         .template("{spinner:.green} [{elapsed_precise}] [{bar:40.cyan/blue}] {bytes}/{total_bytes} ({eta})").expect("Failed to set progress bar style")
         .progress_chars("#>-"));
-    let source = client.get(url).send().await?;
-    if !source.status().is_success() {
-        return Err(InstallerError::Custom(format!(
-            "Bad Response {}",
-            source.status()
-        )));
+    // The whole transfer is retried. A connection reset while streaming restarts the file
+    let mut attempt = 1;
+    let actual = loop {
+        match stream_to_file(client, &url, archive, &pb).await {
+            Err(InstallerError::Adoptium(error))
+                if attempt < retry.max_attempts && RetryPolicy::is_retryable(&error) =>
+            {
+                let delay = retry.delay(attempt, &error);
+                warn!(%error, ?delay, attempt, "Download failed. Retrying");
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            result => break result?,
+        }
+    };
+
+    if !actual.eq_ignore_ascii_case(&expected) {
+        return Err(InstallerError::ChecksumMismatch {
+            file: package.name.clone(),
+            expected,
+            actual,
+        });
     }
-    let (mut send, read) = duplex(1024);
-    let handle = tokio::spawn(async move {
-        let decoder = GzipDecoder::new(BufReader::new(read));
-        let mut archive = Archive::new(decoder);
-        archive.unpack(&location).await.unwrap();
-    });
+    Ok(actual)
+}
+
+/// Streams the response into a new file. Returns the SHA-256 of the file
+async fn stream_to_file(
+    client: &Client,
+    url: &Url,
+    archive: &Path,
+    pb: &ProgressBar,
+) -> Result<String, InstallerError> {
+    pb.set_position(0);
+    let source = client
+        .get(url.clone())
+        .send()
+        .await
+        .map_err(AdoptiumError::from)?
+        .into_result()
+        .await?;
+    let mut file = File::create(archive).await?;
+    let mut hasher = Sha256::new();
     let mut stream = source.bytes_stream();
     while let Some(item) = stream.next().await {
-        let chunk: Bytes = item.unwrap();
+        let chunk: Bytes = item.map_err(AdoptiumError::from)?;
         pb.inc(chunk.len() as u64);
-        send.write_all(chunk.as_ref()).await?;
+        hasher.update(&chunk);
+        file.write_all(chunk.as_ref()).await?;
+    }
+    file.flush().await?;
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Uses [Package::checksum] or fetches [Package::checksum_link] when it is missing
+async fn expected_checksum(
+    client: &Client,
+    package: &Package,
+    retry: &RetryPolicy,
+) -> Result<String, InstallerError> {
+    if let Some(checksum) = package.checksum.as_deref().filter(|v| !v.is_empty()) {
+        return Ok(checksum.to_string());
+    }
+    let Some(link) = package.checksum_link.as_deref() else {
+        return Err(InstallerError::ChecksumUnavailable(package.name.clone()));
+    };
+    let body = retry
+        .retry(|| async {
+            Ok(client
+                .get(link)
+                .send()
+                .await?
+                .into_result()
+                .await?
+                .text()
+                .await?)
+        })
+        .await?;
+    parse_checksum_file(&body)
+        .ok_or_else(|| InstallerError::ChecksumUnavailable(package.name.clone()))
+}
+
+/// Parses the `sha256sum` format. `<checksum>  <file name>`
+fn parse_checksum_file(body: &str) -> Option<String> {
+    body.split_whitespace()
+        .next()
+        .filter(|checksum| checksum.len() == 64 && checksum.chars().all(|c| c.is_ascii_hexdigit()))
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn checksum_file() {
+        let checksum = "aa1c1a7a8b5d5ba9ed3fc6a9a3a2bd9ef4a1ce7d4e7b6a8e4cb37b4b2fe1f0cf";
+        assert_eq!(
+            parse_checksum_file(&format!(
+                "{checksum}  OpenJDK17U-jdk_x64_linux_hotspot_17.0.10_7.tar.gz\n"
+            )),
+            Some(checksum.to_string())
+        );
+        assert_eq!(parse_checksum_file(""), None);
+        assert_eq!(parse_checksum_file("<html>Not Found</html>"), None);
     }
-    handle.await.unwrap();
-    Ok(())
 }
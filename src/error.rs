@@ -2,6 +2,7 @@ use adoptium_api::{
     error::AdoptiumError, requests::release_information::ReleaseInformationParamsBuilderError,
 };
 
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -16,8 +17,46 @@ pub enum InstallerError {
     IOError(#[from] std::io::Error),
     #[error("{0}")]
     Adoptium(#[from] AdoptiumError),
+    #[error("No release found matching {0}")]
+    NoReleaseFound(String),
+    #[error("Checksum mismatch for {file}. Expected {expected} found {actual}")]
+    ChecksumMismatch {
+        file: String,
+        expected: String,
+        actual: String,
+    },
+    #[error("No checksum available for {0}")]
+    ChecksumUnavailable(String),
+    #[error("Signature verification failed for {file}. {reason}")]
+    SignatureVerification { file: String, reason: String },
+    #[error("Invalid archive. {0}")]
+    InvalidArchive(String),
+    #[error("{command} failed with exit code {code}")]
+    CommandFailed { command: String, code: u8 },
+    #[error("{0} is not from a vendor of the Adoptium API and can not be updated")]
+    NotUpdatable(String),
+    #[error("{0} already exists and is not managed by adoptiummd")]
+    LinkConflict(PathBuf),
     #[error("Missing parameter {0}")]
     MissingParameter(#[from] ReleaseInformationParamsBuilderError),
+    #[error("{error}. Restoring the previous state failed. {rollback}")]
+    RollbackFailed {
+        error: Box<InstallerError>,
+        rollback: Box<InstallerError>,
+    },
+}
+
+impl InstallerError {
+    /// Attaches the failure of restoring the previous state to the error that caused it
+    pub fn with_rollback(self, rollback: Result<(), InstallerError>) -> InstallerError {
+        match rollback {
+            Ok(()) => self,
+            Err(rollback) => InstallerError::RollbackFailed {
+                error: Box::new(self),
+                rollback: Box::new(rollback),
+            },
+        }
+    }
 }
 
 impl From<reqwest::Error> for InstallerError {
@@ -2,9 +2,13 @@
 
 use crate::config::{get_config_directory, InstallConfig, Settings};
 use crate::error::InstallerError;
+use crate::sys::{SysConfig, SysInstaller};
+
+use adoptium_api::Adoptium;
+use chrono::{DateTime, Utc};
 
 use std::fmt::{Display, Formatter};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tabled::Tabled;
 use tokio::fs::{create_dir_all, OpenOptions};
 use tokio::io::AsyncWriteExt;
@@ -12,9 +16,11 @@ use tokio::process::Command;
 
 pub mod config;
 
+pub mod archive;
 pub mod commands;
 pub mod download;
 pub mod error;
+pub mod signature;
 pub mod sys;
 pub mod utils;
 
@@ -32,9 +38,10 @@ impl PartialEq<InstallConfig> for Install {
 }
 
 impl Install {
-    pub async fn update(&mut self) -> Result<(), InstallerError> {
+    pub async fn update(&self) -> Result<(), InstallerError> {
         let mut file = OpenOptions::new()
             .create(true)
+            .truncate(true)
             .write(true)
             .open(&self.install_file)
             .await?;
@@ -42,6 +49,51 @@ impl Install {
         file.write_all(value.as_bytes()).await?;
         Ok(())
     }
+
+    /// Swaps `source` into the install location and saves `config`.
+    /// `source` is removed afterwards unless `keep_source` is set.
+    ///
+    /// The previous install is moved to `retain` or removed. Everything is restored if any step fails
+    pub async fn replace(
+        &mut self,
+        config: InstallConfig,
+        source: PathBuf,
+        keep_source: bool,
+        system: &SysConfig,
+        retain: Option<&Path>,
+    ) -> Result<(), InstallerError> {
+        let previous = std::mem::replace(&mut self.config, config);
+        let mut installer = if keep_source {
+            SysInstaller::from_existing(&self.config, source)
+        } else {
+            SysInstaller::new(&self.config, source)
+        };
+        let result = async {
+            if !keep_source {
+                installer.find_internal_data().await?;
+            }
+            installer.swap_in().await?;
+            installer.update_system(system).await?;
+            self.update().await
+        }
+        .await;
+        match result {
+            Ok(()) => installer.commit(retain).await,
+            Err(error) => {
+                let mut rollback = installer.rollback(system).await;
+                if rollback.is_ok() {
+                    // Registers the restored install again. Its priority, slaves and man pages may differ
+                    rollback =
+                        SysInstaller::from_existing(&previous, previous.install_location.clone())
+                            .update_system(system)
+                            .await;
+                }
+                self.config = previous;
+                let rollback = rollback.and(self.update().await);
+                Err(error.with_rollback(rollback))
+            }
+        }
+    }
 }
 
 impl From<(PathBuf, InstallConfig)> for Install {
@@ -55,10 +107,18 @@ impl From<(PathBuf, InstallConfig)> for Install {
 
 pub struct Installer {
     pub settings: Settings,
+    pub adoptium: Adoptium,
     pub installs: Vec<Install>,
 }
 
 impl Installer {
+    /// If the install was selected with `use`
+    pub fn is_default(&self, config: &InstallConfig) -> bool {
+        self.settings
+            .default_install
+            .as_ref()
+            .is_some_and(|id| config.eq(id))
+    }
     pub fn does_install_exist(&self, config: &InstallConfig) -> bool {
         for x in self.installs.iter() {
             if x.eq(config) {
@@ -75,6 +135,7 @@ impl Installer {
         let install_loc = parents.join(format!("{}.toml", &config));
         let mut file = OpenOptions::new()
             .create(true)
+            .truncate(true)
             .write(true)
             .open(&install_loc)
             .await?;
@@ -91,15 +152,27 @@ impl Installer {
 #[derive(Tabled)]
 pub struct InstallTable<'a> {
     pub version: &'a String,
+    pub released_on: String,
     pub location: &'a str,
     pub installed_on: String,
     pub id: String,
     pub up_to_date: UpToDate,
+    #[tabled(display_with = "display_default")]
+    pub default: bool,
+}
+
+fn display_default(value: &bool) -> String {
+    if *value { "*" } else { "" }.to_string()
 }
 
 pub enum UpToDate {
     Yes,
-    No(String),
+    /// The latest version could not be looked up
+    Unknown,
+    No {
+        latest: String,
+        released_on: DateTime<Utc>,
+    },
 }
 
 impl Display for UpToDate {
@@ -108,8 +181,19 @@ impl Display for UpToDate {
             UpToDate::Yes => {
                 write!(f, "Yes")
             }
-            UpToDate::No(value) => {
-                write!(f, "No(Latest: {})", value)
+            UpToDate::Unknown => {
+                write!(f, "Unknown")
+            }
+            UpToDate::No {
+                latest,
+                released_on,
+            } => {
+                write!(
+                    f,
+                    "No(Latest: {} Released: {})",
+                    latest,
+                    released_on.format("%Y-%m-%d")
+                )
             }
         }
     }
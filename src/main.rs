@@ -4,8 +4,6 @@ use adoptiummd::{commands::*, Installer};
 use adoptiummd::config;
 use clap::{Parser, Subcommand};
 
-use std::path::PathBuf;
-
 #[derive(Parser)]
 #[clap(author, version, about, long_about = None)]
 struct AdoptiumClI {
@@ -20,6 +18,10 @@ enum Subcommands {
     Uninstall(uninstall::UninstallCommand),
     List(list::ListCommand),
     Update(update::UpdateCommand),
+    Stats(stats::StatsCommand),
+    Bundle(bundle::BundleCommand),
+    Rollback(rollback::RollbackCommand),
+    Use(use_install::UseCommand),
 }
 
 #[tokio::main]
@@ -33,11 +35,7 @@ async fn main() {
     let config = if let Some(value) = option {
         value
     } else {
-        let settings = Settings {
-            install_location: PathBuf::from("/").join("usr").join("lib").join("jvm"),
-            default_version: None,
-            system: Default::default(),
-        };
+        let settings = Settings::default();
         save_settings(&settings)
             .await
             .expect("Unable to save config");
@@ -50,15 +48,24 @@ async fn main() {
         .into_iter()
         .map(|value| value.into())
         .collect();
+    let adoptium = config.adoptium().expect("Unable to create Adoptium client");
     let app = Installer {
         settings: config,
+        adoptium,
         installs: vec,
     };
-    match value.command {
+    let result = match value.command {
         Subcommands::Install(value) => install::execute(app, value).await,
         Subcommands::Uninstall(value) => uninstall::execute(app, value).await,
         Subcommands::List(value) => list::execute(app, value).await,
         Subcommands::Update(value) => update::execute(app, value).await,
+        Subcommands::Stats(value) => stats::execute(app, value).await,
+        Subcommands::Bundle(value) => bundle::execute(app, value).await,
+        Subcommands::Rollback(value) => rollback::execute(app, value).await,
+        Subcommands::Use(value) => use_install::execute(app, value).await,
+    };
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        std::process::exit(1);
     }
-    .unwrap();
 }
@@ -0,0 +1,289 @@
+use crate::config::SignatureSettings;
+use crate::error::InstallerError;
+use crate::run_command;
+use adoptium_api::error::IntoResult;
+use adoptium_api::response::Package;
+use adoptium_api::RetryPolicy;
+
+use reqwest::Client;
+use std::io::ErrorKind;
+use std::path::Path;
+use tokio::fs::{create_dir_all, remove_file};
+use tokio::process::Command;
+use tracing::warn;
+
+/// The key Adoptium signs its binaries with
+pub const ADOPTIUM_KEY_FINGERPRINT: &str = "3B04D753C9050D9A5D343F39843C48A565F8F04B";
+
+/// The ASCII armored Adoptium key. Written to the keyring when it does not exist
+pub const ADOPTIUM_PUBLIC_KEY: &str = include_str!("../keys/adoptium.asc");
+
+/// Verifies the detached signatures of downloaded packages with `gpgv`
+pub struct SignatureVerifier<'a> {
+    pub settings: &'a SignatureSettings,
+    /// Continue with a warning when verification fails
+    pub allow_unverified: bool,
+    /// Used instead of the configured keyring when that does not exist. Such as the keyring of a bundle
+    pub fallback_keyring: Option<&'a Path>,
+}
+
+impl<'a> SignatureVerifier<'a> {
+    pub fn new(settings: &'a SignatureSettings, allow_unverified: bool) -> Self {
+        SignatureVerifier {
+            settings,
+            allow_unverified,
+            fallback_keyring: None,
+        }
+    }
+
+    pub fn with_fallback_keyring(mut self, keyring: Option<&'a Path>) -> Self {
+        self.fallback_keyring = keyring;
+        self
+    }
+
+    /// Fetches the signature of the package and verifies the archive with it
+    pub async fn verify(
+        &self,
+        client: &Client,
+        retry: &RetryPolicy,
+        package: &Package,
+        archive: &Path,
+    ) -> Result<(), InstallerError> {
+        if !self.settings.enabled {
+            return Ok(());
+        }
+        let signature_file = archive.with_file_name(format!("{}.sig", package.name));
+        let result = async {
+            fetch_signature(client, retry, package, &signature_file).await?;
+            self.check(&package.name, &signature_file, archive).await
+        }
+        .await;
+        if signature_file.exists() {
+            remove_file(&signature_file).await?;
+        }
+        self.apply_policy(result)
+    }
+
+    /// Verifies the archive with a signature that is already on disk
+    pub async fn verify_local(
+        &self,
+        name: &str,
+        signature: Option<&Path>,
+        archive: &Path,
+    ) -> Result<(), InstallerError> {
+        if !self.settings.enabled {
+            return Ok(());
+        }
+        let result = match signature {
+            Some(signature) => self.check(name, signature, archive).await,
+            None => Err(InstallerError::SignatureVerification {
+                file: name.to_string(),
+                reason: "No signature is available".to_string(),
+            }),
+        };
+        self.apply_policy(result)
+    }
+
+    fn apply_policy(&self, result: Result<(), InstallerError>) -> Result<(), InstallerError> {
+        match result {
+            Err(InstallerError::SignatureVerification { file, reason })
+                if self.allow_unverified =>
+            {
+                warn!(file, reason, "Signature verification failed");
+                println!("Signature verification of {file} failed. Continuing anyway. {reason}");
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
+    async fn check(
+        &self,
+        name: &str,
+        signature: &Path,
+        archive: &Path,
+    ) -> Result<(), InstallerError> {
+        let keyring = self.keyring().await?;
+        let code = run_gpg(
+            Command::new("gpgv")
+                .arg("--keyring")
+                .arg(keyring)
+                .arg(signature)
+                .arg(archive),
+            name,
+        )
+        .await?;
+        if code != 0 {
+            return Err(InstallerError::SignatureVerification {
+                file: name.to_string(),
+                reason: format!("Bad signature for keyring {}", keyring.display()),
+            });
+        }
+        Ok(())
+    }
+
+    async fn keyring(&self) -> Result<&Path, InstallerError> {
+        match self.fallback_keyring {
+            Some(fallback) if !self.settings.keyring.exists() => Ok(fallback),
+            _ => {
+                self.ensure_keyring().await?;
+                Ok(&self.settings.keyring)
+            }
+        }
+    }
+
+    /// Creates the keyring when it does not exist.
+    ///
+    /// From the embedded Adoptium key or, if one is configured, the keyserver
+    pub async fn ensure_keyring(&self) -> Result<(), InstallerError> {
+        let keyring = &self.settings.keyring;
+        if keyring.exists() {
+            return Ok(());
+        }
+        let result = async {
+            if let Some(parent) = keyring.parent() {
+                create_dir_all(parent).await?;
+            }
+            match &self.settings.keyserver {
+                Some(keyserver) => self.receive_key(keyserver).await,
+                None => self.write_embedded_key().await,
+            }
+        }
+        .await;
+        result.map_err(|error| match error {
+            InstallerError::SignatureVerification { .. } => error,
+            error => InstallerError::SignatureVerification {
+                file: keyring.display().to_string(),
+                reason: format!("Unable to create the keyring. {error}"),
+            },
+        })
+    }
+
+    async fn write_embedded_key(&self) -> Result<(), InstallerError> {
+        let keyring = &self.settings.keyring;
+        if !ADOPTIUM_PUBLIC_KEY.starts_with("-----BEGIN PGP PUBLIC KEY BLOCK-----") {
+            return Err(InstallerError::SignatureVerification {
+                file: keyring.display().to_string(),
+                reason: "This build does not embed the Adoptium key. Configure a keyserver"
+                    .to_string(),
+            });
+        }
+        println!("Writing the Adoptium key into {}", keyring.display());
+        let armored = keyring.with_extension("asc");
+        tokio::fs::write(&armored, ADOPTIUM_PUBLIC_KEY).await?;
+        let code = run_gpg(
+            Command::new("gpg")
+                .arg("--batch")
+                .arg("--yes")
+                .arg("--output")
+                .arg(keyring)
+                .arg("--dearmor")
+                .arg(&armored),
+            &keyring.display().to_string(),
+        )
+        .await;
+        remove_file(&armored).await?;
+        if code? != 0 {
+            return Err(InstallerError::SignatureVerification {
+                file: keyring.display().to_string(),
+                reason: "Unable to write the Adoptium key".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn receive_key(&self, keyserver: &str) -> Result<(), InstallerError> {
+        let keyring = &self.settings.keyring;
+        let Some(fingerprint) = self.settings.default_key.as_deref() else {
+            return Err(InstallerError::SignatureVerification {
+                file: keyring.display().to_string(),
+                reason: "The keyring does not exist".to_string(),
+            });
+        };
+        println!(
+            "Importing key {fingerprint} from {keyserver} into {}",
+            keyring.display()
+        );
+        let code = run_gpg(
+            Command::new("gpg")
+                .arg("--batch")
+                .arg("--no-default-keyring")
+                .arg("--keyring")
+                .arg(keyring)
+                .arg("--keyserver")
+                .arg(keyserver)
+                .arg("--recv-keys")
+                .arg(fingerprint),
+            &keyring.display().to_string(),
+        )
+        .await?;
+        if code != 0 {
+            return Err(InstallerError::SignatureVerification {
+                file: keyring.display().to_string(),
+                reason: format!("Unable to import key {fingerprint}"),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Runs `gpg` or `gpgv`. Reports a missing GnuPG as a failed verification so it can be overridden
+async fn run_gpg(command: &mut Command, file: &str) -> Result<u8, InstallerError> {
+    run_command(command).await.map_err(|error| match error {
+        InstallerError::IOError(error) if error.kind() == ErrorKind::NotFound => {
+            InstallerError::SignatureVerification {
+                file: file.to_string(),
+                reason: format!(
+                    "{} is not installed",
+                    command.as_std().get_program().to_string_lossy()
+                ),
+            }
+        }
+        error => error,
+    })
+}
+
+/// Downloads the detached signature of the package to `destination`
+pub async fn fetch_signature(
+    client: &Client,
+    retry: &RetryPolicy,
+    package: &Package,
+    destination: &Path,
+) -> Result<(), InstallerError> {
+    let Some(link) = package.signature_link.as_deref() else {
+        return Err(InstallerError::SignatureVerification {
+            file: package.name.clone(),
+            reason: "No signature is published".to_string(),
+        });
+    };
+    let signature = retry
+        .retry(|| async {
+            Ok(client
+                .get(link)
+                .send()
+                .await?
+                .into_result()
+                .await?
+                .bytes()
+                .await?)
+        })
+        .await?;
+    tokio::fs::write(destination, &signature).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn embeds_the_adoptium_key() {
+        assert!(
+            ADOPTIUM_PUBLIC_KEY.starts_with("-----BEGIN PGP PUBLIC KEY BLOCK-----"),
+            "keys/adoptium.asc is not an ASCII armored public key"
+        );
+        assert!(ADOPTIUM_PUBLIC_KEY
+            .trim_end()
+            .ends_with("-----END PGP PUBLIC KEY BLOCK-----"));
+    }
+}
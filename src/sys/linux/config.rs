@@ -1,4 +1,8 @@
+use super::discover_executables;
+use crate::{InstallConfig, InstallerError};
+use adoptium_api::response::VersionData;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct SysConfig {
@@ -9,6 +13,8 @@ pub struct SysConfig {
 #[serde(tag = "type", content = "values")]
 pub enum InstallMethod {
     UpdateAlternatives(UpdateAlternatives),
+    /// For systems without update-alternatives. Such as Alpine and Arch
+    Symlink(Symlink),
 }
 
 impl Default for InstallMethod {
@@ -18,38 +24,236 @@ impl Default for InstallMethod {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
 pub struct UpdateAlternatives {
-    pub jre_paths: Vec<UpdateAlternativePath>,
-    pub jdk_paths: Vec<UpdateAlternativePath>,
+    /// The executable of the group. The other executables in `bin` are its slaves and switch with it
+    pub master: String,
+    /// Where the links are created. Such as `/usr/bin/java`
+    pub link_directory: PathBuf,
+    /// Executables in `bin` that are registered. `*` matches any characters and `?` one character
+    pub include: Vec<String>,
+    /// Executables in `bin` that are never registered. Takes precedence over `include`
+    pub exclude: Vec<String>,
+    /// The administrative directory of update-alternatives.
+    ///
+    /// Executables that already have their own group in it, such as `javac` of a distribution JDK, are registered in that group instead of as a slave.
+    /// Detected from the locations of Debian and RHEL based distributions when not set
+    pub admin_directory: Option<PathBuf>,
+    /// How the priority of an install is picked. Auto mode selects the highest priority
+    pub priority: PriorityStrategy,
+    /// Registers the `man/man1` page of each executable with it
+    pub man_pages: bool,
+    /// Where man page links are created. Such as `/usr/share/man/man1/java.1.gz`
+    pub man_directory: PathBuf,
+    /// Links gzip compressed man pages. Detected from the pages in `man_directory` when not set
+    pub compress_man_pages: Option<bool>,
 }
 
+impl UpdateAlternatives {
+    /// If the executable matches `include` and not `exclude`
+    pub fn includes(&self, name: &str) -> bool {
+        matches_patterns(&self.include, &self.exclude, name)
+    }
+    /// If `name` has its own group. So it can not be a slave of [UpdateAlternatives::master]
+    pub fn is_master_group(&self, name: &str) -> bool {
+        name != self.master
+            && self
+                .admin_directory()
+                .is_some_and(|directory| directory.join(name).exists())
+    }
+    /// How the included executables of the install are registered.
+    ///
+    /// `None` if the install is neither a JDK nor a JRE or does not have [UpdateAlternatives::master]
+    pub async fn groups(
+        &self,
+        install: &InstallConfig,
+    ) -> Result<Option<AlternativeGroups>, InstallerError> {
+        let Some(executables) =
+            discover_executables(install, &self.master, |name| self.includes(name)).await?
+        else {
+            return Ok(None);
+        };
+        let (masters, slaves): (Vec<_>, Vec<_>) = executables
+            .into_iter()
+            .partition(|name| self.is_master_group(name));
+        Ok(Some(AlternativeGroups {
+            masters: std::iter::once(self.master.clone())
+                .chain(masters)
+                .collect(),
+            slaves,
+        }))
+    }
+    /// If man pages are linked compressed. Otherwise if the distribution's pages in `man_directory/man1` are `.gz`
+    pub fn compress_man_pages(&self) -> bool {
+        self.compress_man_pages.unwrap_or_else(|| {
+            std::fs::read_dir(self.man_directory.join("man1")).is_ok_and(|entries| {
+                entries
+                    .flatten()
+                    .any(|entry| entry.file_name().to_string_lossy().ends_with(".1.gz"))
+            })
+        })
+    }
+    /// The configured administrative directory or the first of [ADMIN_DIRECTORIES] that exists
+    pub fn admin_directory(&self) -> Option<PathBuf> {
+        match &self.admin_directory {
+            Some(directory) => Some(directory.clone()),
+            None => ADMIN_DIRECTORIES
+                .iter()
+                .map(PathBuf::from)
+                .find(|directory| directory.is_dir()),
+        }
+    }
+}
+
+/// The executables of an install split into update-alternatives groups
+pub struct AlternativeGroups {
+    /// [UpdateAlternatives::master] followed by the executables that have their own group
+    pub masters: Vec<String>,
+    /// Switch with [UpdateAlternatives::master]
+    pub slaves: Vec<String>,
+}
+
+/// The administrative directories of update-alternatives on Debian and on RHEL and Fedora
+pub const ADMIN_DIRECTORIES: [&str; 2] = ["/var/lib/dpkg/alternatives", "/var/lib/alternatives"];
+
+/// Links the executables of the selected install into a directory
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct UpdateAlternativePath {
-    pub system_path: String,
-    pub exec_name: String,
+#[serde(default)]
+pub struct Symlink {
+    /// Where the links are created. Existing files that are not links into the install location are never replaced
+    pub bin_directory: PathBuf,
+    /// Installs without this executable are not linked
+    pub master: String,
+    /// Executables in `bin` that are linked. `*` matches any characters and `?` one character
+    pub include: Vec<String>,
+    /// Executables in `bin` that are never linked. Takes precedence over `include`
+    pub exclude: Vec<String>,
 }
 
-impl From<(&str, &str)> for UpdateAlternativePath {
-    fn from((sys, internal): (&str, &str)) -> Self {
-        Self {
-            system_path: sys.to_string(),
-            exec_name: internal.to_string(),
+impl Default for Symlink {
+    fn default() -> Self {
+        Symlink {
+            bin_directory: PathBuf::from("/usr/local/bin"),
+            master: "java".to_string(),
+            include: vec!["*".to_string()],
+            exclude: Vec::new(),
         }
     }
 }
 
+impl Symlink {
+    /// If the executable matches `include` and not `exclude`
+    pub fn includes(&self, name: &str) -> bool {
+        matches_patterns(&self.include, &self.exclude, name)
+    }
+}
+
+fn matches_patterns(include: &[String], exclude: &[String], name: &str) -> bool {
+    let matches = |patterns: &[String]| {
+        patterns
+            .iter()
+            .any(|pattern| wildcard_match(pattern.as_bytes(), name.as_bytes()))
+    };
+    matches(include) && !matches(exclude)
+}
+
+fn wildcard_match(pattern: &[u8], value: &[u8]) -> bool {
+    match (pattern.split_first(), value.split_first()) {
+        (None, None) => true,
+        (Some((b'*', rest)), _) => {
+            wildcard_match(rest, value)
+                || (!value.is_empty() && wildcard_match(pattern, &value[1..]))
+        }
+        (Some((b'?', rest)), Some((_, value))) => wildcard_match(rest, value),
+        (Some((expected, rest)), Some((actual, value))) if expected == actual => {
+            wildcard_match(rest, value)
+        }
+        _ => false,
+    }
+}
+
+/// The priority an install is registered with
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum PriorityStrategy {
+    /// The newest version has the highest priority
+    #[default]
+    Newest,
+    /// LTS versions are above every other version. The newest LTS is the highest
+    PreferLts,
+    /// Every install has the same priority
+    Fixed(i32),
+}
+
+impl PriorityStrategy {
+    /// Added to LTS versions by [PriorityStrategy::PreferLts]
+    const LTS_BONUS: i64 = 100_000_000;
+
+    pub fn priority(&self, version: &VersionData) -> i64 {
+        match self {
+            PriorityStrategy::Newest => Self::version_priority(version),
+            PriorityStrategy::PreferLts if version.is_lts() => {
+                Self::LTS_BONUS + Self::version_priority(version)
+            }
+            PriorityStrategy::PreferLts => Self::version_priority(version),
+            PriorityStrategy::Fixed(priority) => i64::from(*priority),
+        }
+    }
+
+    /// `major * 100000 + security * 100 + build`
+    fn version_priority(version: &VersionData) -> i64 {
+        version.major * 100_000 + version.security.clamp(0, 999) * 100 + version.build.clamp(0, 99)
+    }
+}
+
 impl Default for UpdateAlternatives {
     fn default() -> Self {
         UpdateAlternatives {
-            jre_paths: vec![("/usr/bin/java", "java").into()],
-            jdk_paths: vec![
-                ("/usr/bin/java", "java").into(),
-                ("/usr/bin/javac", "javac").into(),
-                ("/usr/bin/javadoc", "javadoc").into(),
-                ("/usr/bin/javah", "javah").into(),
-                ("/usr/bin/javap", "javap").into(),
-                ("/usr/bin/javaws", "javaws").into(),
-            ],
+            master: "java".to_string(),
+            link_directory: PathBuf::from("/usr/bin"),
+            include: vec!["*".to_string()],
+            exclude: Vec::new(),
+            admin_directory: None,
+            priority: PriorityStrategy::default(),
+            man_pages: true,
+            man_directory: PathBuf::from("/usr/share/man"),
+            compress_man_pages: None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn priorities() {
+        let lts: VersionData = (21, 13, 0, 2).into();
+        let newer: VersionData = (22, 7, 0, 1).into();
+        let newest = PriorityStrategy::Newest;
+        assert_eq!(newest.priority(&lts), 2_100_213);
+        assert!(newest.priority(&newer) > newest.priority(&lts));
+        let prefer_lts = PriorityStrategy::PreferLts;
+        assert!(prefer_lts.priority(&lts) > prefer_lts.priority(&newer));
+        assert_eq!(PriorityStrategy::Fixed(1).priority(&newer), 1);
+    }
+
+    #[test]
+    pub fn include_exclude() {
+        let alternatives = UpdateAlternatives {
+            exclude: vec!["*.so".to_string(), "jaotc".to_string()],
+            ..Default::default()
+        };
+        assert!(alternatives.includes("jshell"));
+        assert!(!alternatives.includes("jaotc"));
+        assert!(!alternatives.includes("libjli.so"));
+        let alternatives = UpdateAlternatives {
+            include: vec!["java?".to_string(), "j*l".to_string()],
+            ..Default::default()
+        };
+        assert!(alternatives.includes("javac"));
+        assert!(alternatives.includes("jshell"));
+        assert!(!alternatives.includes("java"));
+        assert!(!alternatives.includes("keytool"));
+    }
+}
@@ -1,7 +1,10 @@
-use crate::{run_command, Install, InstallConfig, InstallerError};
+use crate::config::RetainedVersion;
+use crate::{archive, run_command, Install, InstallConfig, InstallerError};
 use futures_util::StreamExt;
-use std::path::PathBuf;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
 
+use adoptium_api::response::VersionData;
 use adoptium_api::types::ImageType;
 use tokio::fs::{create_dir_all, read_dir, remove_dir_all, rename};
 use tokio::process::Command;
@@ -9,22 +12,103 @@ use tokio_stream::wrappers::ReadDirStream;
 
 pub mod config;
 
-use self::config::InstallMethod;
+use self::config::{InstallMethod, UpdateAlternatives};
+
+mod symlink;
 
 use super::SysConfig;
 
 pub struct SysInstaller<'a> {
     pub extracted_data: PathBuf,
     pub install_data: &'a InstallConfig,
+    /// The directory the data was extracted into
+    staging: PathBuf,
+    /// Removed by [SysInstaller::commit] and [SysInstaller::rollback]
+    remove_staging: bool,
+    /// The previous install while it is being replaced
+    backup: Option<PathBuf>,
+    swapped: bool,
+}
+
+/// A hidden directory next to the install location
+fn sibling(install_location: &Path, suffix: &str) -> PathBuf {
+    let name = install_location
+        .file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    install_location.with_file_name(format!(".{name}.{suffix}"))
+}
+
+/// The executables in `bin` of a JDK or JRE other than the master that are included.
+///
+/// `None` if the install is neither or does not have the master executable
+async fn discover_executables(
+    install: &InstallConfig,
+    master: &str,
+    includes: impl Fn(&str) -> bool,
+) -> Result<Option<Vec<String>>, InstallerError> {
+    if !matches!(
+        install.install_settings.image_type,
+        ImageType::JDK | ImageType::JRE
+    ) {
+        return Ok(None);
+    }
+    let bin = install.install_location.join("bin");
+    if !bin.join(master).exists() {
+        return Ok(None);
+    }
+    let mut executables = Vec::new();
+    let mut stream = ReadDirStream::new(read_dir(&bin).await?);
+    while let Some(value) = stream.next().await {
+        let entry = value?;
+        let metadata = entry.metadata().await?;
+        if !metadata.is_file() || metadata.permissions().mode() & 0o111 == 0 {
+            continue;
+        }
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name != master && includes(&name) {
+            executables.push(name);
+        }
+    }
+    executables.sort();
+    Ok(Some(executables))
 }
 
 impl<'a> SysInstaller<'a> {
-    pub fn new(data: &'a InstallConfig, extracted: PathBuf) -> SysInstaller {
+    pub fn new(data: &'a InstallConfig, extracted: PathBuf) -> SysInstaller<'a> {
         SysInstaller {
+            staging: extracted.clone(),
+            remove_staging: true,
             extracted_data: extracted,
             install_data: data,
+            backup: None,
+            swapped: false,
         }
     }
+    /// Swaps in a directory that is kept. Such as a retained version
+    pub fn from_existing(data: &'a InstallConfig, location: PathBuf) -> SysInstaller<'a> {
+        SysInstaller {
+            remove_staging: false,
+            ..SysInstaller::new(data, location)
+        }
+    }
+    /// Where new data is extracted before it is swapped in.
+    /// On the same file system as the install so it can be renamed
+    pub fn staging_directory(install_location: &Path) -> PathBuf {
+        sibling(install_location, "staging")
+    }
+    /// Where the archive of the install and its signature are downloaded
+    pub fn download_directory(install_location: &Path) -> PathBuf {
+        sibling(install_location, "download")
+    }
+    /// Where compressed man pages of the install are written. The install itself is not changed
+    pub fn compressed_man_directory(install_location: &Path) -> PathBuf {
+        sibling(install_location, "man")
+    }
+    /// Where a previous version is kept after an update
+    pub fn retained_directory(install_location: &Path, version: &VersionData) -> PathBuf {
+        sibling(install_location, &version.semver)
+    }
     pub async fn find_internal_data(&mut self) -> Result<(), InstallerError> {
         let mut stream = ReadDirStream::new(read_dir(&self.extracted_data).await?);
         while let Some(value) = stream.next().await {
@@ -37,16 +121,98 @@ impl<'a> SysInstaller<'a> {
         }
         Ok(())
     }
-    pub async fn move_data(&self) -> Result<(), InstallerError> {
-        let install_location = self.install_data.install_location.clone();
+    /// Replaces the install location with the extracted data.
+    ///
+    /// The current install is kept until [SysInstaller::commit] or restored by [SysInstaller::rollback]
+    pub async fn swap_in(&mut self) -> Result<(), InstallerError> {
+        let install_location = &self.install_data.install_location;
+        if let Some(parent) = install_location.parent() {
+            create_dir_all(parent).await?;
+        }
         if install_location.exists() {
-            remove_dir_all(&install_location).await?;
+            let backup = sibling(install_location, "previous");
+            if backup.exists() {
+                remove_dir_all(&backup).await?;
+            }
+            rename(install_location, &backup).await?;
+            self.backup = Some(backup);
         }
-        create_dir_all(&install_location).await?;
-        let mut stream = ReadDirStream::new(read_dir(&self.extracted_data).await?);
-        while let Some(value) = stream.next().await {
-            let entry = value?;
-            rename(entry.path(), install_location.join(entry.file_name())).await?;
+        if let Err(error) = rename(&self.extracted_data, install_location).await {
+            let error = InstallerError::from(error);
+            return Err(match self.backup.take() {
+                Some(backup) => {
+                    let restored = rename(&backup, install_location).await;
+                    error.with_rollback(restored.map_err(Into::into))
+                }
+                None => error,
+            });
+        }
+        self.swapped = true;
+        Ok(())
+    }
+    /// Removes the staging directory and the previous install or moves it to `retain`
+    pub async fn commit(self, retain: Option<&Path>) -> Result<(), InstallerError> {
+        match (&self.backup, retain) {
+            (Some(backup), Some(retain)) => {
+                if retain.exists() {
+                    remove_dir_all(retain).await?;
+                }
+                rename(backup, retain).await?;
+            }
+            (Some(backup), None) => remove_dir_all(backup).await?,
+            (None, _) => {}
+        }
+        if self.remove_staging && self.staging.exists() {
+            remove_dir_all(&self.staging).await?;
+        }
+        Ok(())
+    }
+    /// Restores the state from before [SysInstaller::swap_in].
+    ///
+    /// Every step is attempted even if an earlier one fails. Returns the first failure
+    pub async fn rollback(self, config: &SysConfig) -> Result<(), InstallerError> {
+        println!("Restoring the previous state");
+        let install_location = &self.install_data.install_location;
+        let mut errors: Vec<InstallerError> = Vec::new();
+        if self.swapped {
+            if self.backup.is_none() {
+                errors.extend(Self::unregister(config, self.install_data).await.err());
+            }
+            if let Err(error) = rename(install_location, &self.extracted_data).await {
+                errors.push(error.into());
+                // The new data must not stay in the way of the backup
+                if self.backup.is_some() && install_location.exists() {
+                    errors.extend(remove_dir_all(install_location).await.err().map(Into::into));
+                }
+            }
+        }
+        if let Some(backup) = &self.backup {
+            errors.extend(rename(backup, install_location).await.err().map(Into::into));
+        }
+        if self.remove_staging && self.staging.exists() {
+            errors.extend(remove_dir_all(&self.staging).await.err().map(Into::into));
+        }
+        let mut errors = errors.into_iter();
+        let Some(error) = errors.next() else {
+            return Ok(());
+        };
+        for other in errors {
+            println!("Restoring the previous state also failed. {other}");
+        }
+        Err(error)
+    }
+    /// Removes the directories of the pruned versions. Unless a retained version is kept in the same directory
+    pub async fn remove_pruned(
+        pruned: &[RetainedVersion],
+        retained: &[RetainedVersion],
+    ) -> Result<(), InstallerError> {
+        for version in pruned {
+            let kept = retained
+                .iter()
+                .any(|retained| retained.location == version.location);
+            if !kept && version.location.exists() {
+                remove_dir_all(&version.location).await?;
+            }
         }
         Ok(())
     }
@@ -54,36 +220,100 @@ impl<'a> SysInstaller<'a> {
         config: &SysConfig,
         install: &Install,
     ) -> Result<(), InstallerError> {
+        Self::unregister(config, &install.config).await
+    }
+    async fn unregister(config: &SysConfig, install: &InstallConfig) -> Result<(), InstallerError> {
         match &config.install_method {
-            InstallMethod::UpdateAlternatives(alt) => {
-                let paths = match install.config.install_settings.image_type {
-                    ImageType::JDK => Some(&alt.jdk_paths),
-                    ImageType::JRE => Some(&alt.jre_paths),
-                    _ => None,
+            InstallMethod::UpdateAlternatives(value) => {
+                let man = Self::compressed_man_directory(&install.install_location);
+                if man.exists() {
+                    remove_dir_all(&man).await?;
+                }
+                let Some(groups) = value.groups(install).await? else {
+                    return Ok(());
                 };
-                if let Some(paths) = paths {
-                    for up_a in paths {
-                        let path = install
-                            .config
-                            .install_location
-                            .join("bin")
-                            .join(&up_a.exec_name);
-                        let code = run_command(
-                            Command::new("update-alternatives")
-                                .arg("--remove")
-                                .arg(&up_a.exec_name)
-                                .arg(path.as_os_str()),
-                        )
-                        .await?;
-                        if code != 0 {
-                            //TODO handle Command Error
-                        }
+                let bin = install.install_location.join("bin");
+                // Removing the master removes its slaves
+                for name in &groups.masters {
+                    let code = run_command(
+                        Command::new("update-alternatives")
+                            .arg("--remove")
+                            .arg(name)
+                            .arg(bin.join(name).as_os_str()),
+                    )
+                    .await?;
+                    if code != 0 {
+                        //TODO handle Command Error
                     }
                 }
             }
+            InstallMethod::Symlink(value) => symlink::unlink(value, install).await?,
         }
         Ok(())
     }
+    /// Makes the install the system default with `update-alternatives --set` or by linking it
+    pub async fn set_default(
+        config: &SysConfig,
+        install: &InstallConfig,
+    ) -> Result<(), InstallerError> {
+        match &config.install_method {
+            InstallMethod::UpdateAlternatives(value) => {
+                let Some(groups) = value.groups(install).await? else {
+                    return Ok(());
+                };
+                let bin = install.install_location.join("bin");
+                for name in &groups.masters {
+                    let code = run_command(
+                        Command::new("update-alternatives")
+                            .arg("--set")
+                            .arg(name)
+                            .arg(bin.join(name).as_os_str()),
+                    )
+                    .await?;
+                    if code != 0 {
+                        return Err(InstallerError::CommandFailed {
+                            command: format!("update-alternatives --set {name}"),
+                            code,
+                        });
+                    }
+                }
+            }
+            InstallMethod::Symlink(value) => symlink::link(value, install).await?,
+        }
+        Ok(())
+    }
+    /// The man page of the executable as a slave. `(link, name, path)`
+    ///
+    /// Compressed into [SysInstaller::compressed_man_directory] when [UpdateAlternatives::compress_man_pages] is
+    async fn man_page(
+        &self,
+        alternatives: &UpdateAlternatives,
+        name: &str,
+    ) -> Result<Option<(PathBuf, String, PathBuf)>, InstallerError> {
+        if !alternatives.man_pages {
+            return Ok(None);
+        }
+        let install_location = &self.install_data.install_location;
+        let mut page = format!("{name}.1");
+        let mut path = install_location.join("man").join("man1").join(&page);
+        if !path.exists() {
+            return Ok(None);
+        }
+        if alternatives.compress_man_pages() {
+            let directory = Self::compressed_man_directory(install_location);
+            create_dir_all(&directory).await?;
+            page = format!("{page}.gz");
+            // Written again so an update replaces the pages of the previous version
+            let compressed = directory.join(&page);
+            archive::gzip(&path, &compressed).await?;
+            path = compressed;
+        }
+        Ok(Some((
+            alternatives.man_directory.join("man1").join(&page),
+            page,
+            path,
+        )))
+    }
     pub async fn update_system(&self, config: &SysConfig) -> Result<(), InstallerError> {
         run_command(
             Command::new("chmod")
@@ -95,34 +325,240 @@ impl<'a> SysInstaller<'a> {
 
         match &config.install_method {
             InstallMethod::UpdateAlternatives(value) => {
-                let paths = match self.install_data.install_settings.image_type {
-                    ImageType::JDK => &value.jdk_paths,
-                    ImageType::JRE => &value.jre_paths,
-                    _ => {
-                        return Ok(());
-                    }
+                let Some(groups) = value.groups(self.install_data).await? else {
+                    return Ok(());
                 };
-                for value in paths {
-                    let path = self
-                        .install_data
-                        .install_location
-                        .join("bin")
-                        .join(&value.exec_name);
-                    let code = run_command(
-                        Command::new("update-alternatives")
-                            .arg("--install")
-                            .arg(&value.system_path)
-                            .arg(&value.exec_name)
-                            .arg(path.as_os_str())
-                            .arg("1"),
-                    )
-                    .await?;
+                let bin = self.install_data.install_location.join("bin");
+                // Registering a path again replaces its priority. So an update re-prioritizes the install
+                let priority = value
+                    .priority
+                    .priority(&self.install_data.current_version)
+                    .to_string();
+                let install_command = |name: &str| {
+                    let mut command = Command::new("update-alternatives");
+                    command
+                        .arg("--install")
+                        .arg(value.link_directory.join(name))
+                        .arg(name)
+                        .arg(bin.join(name))
+                        .arg(&priority);
+                    command
+                };
+                let mut commands = groups
+                    .masters
+                    .iter()
+                    .map(|name| (name, install_command(name)))
+                    .collect::<Vec<_>>();
+                for name in &groups.slaves {
+                    commands[0]
+                        .1
+                        .arg("--slave")
+                        .arg(value.link_directory.join(name))
+                        .arg(name)
+                        .arg(bin.join(name));
+                }
+                let names = groups
+                    .masters
+                    .iter()
+                    .enumerate()
+                    .chain(groups.slaves.iter().map(|name| (0, name)));
+                for (index, name) in names {
+                    // The man page switches with the executable
+                    if let Some((link, page, path)) = self.man_page(value, name).await? {
+                        commands[index]
+                            .1
+                            .arg("--slave")
+                            .arg(link)
+                            .arg(page)
+                            .arg(path);
+                    }
+                }
+                for (name, mut command) in commands {
+                    let code = run_command(&mut command).await?;
                     if code != 0 {
-                        //TODO handle Command Error
+                        return Err(InstallerError::CommandFailed {
+                            command: format!("update-alternatives --install {name}"),
+                            code,
+                        });
                     }
                 }
             }
+            InstallMethod::Symlink(value) => {
+                symlink::link_if_unselected(value, self.install_data).await?
+            }
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::config::Symlink;
+    use super::*;
+    use crate::config::InstallSettings;
+    use adoptium_api::types::{AdoptiumJvmImpl, HeapSize, Project, ReleaseType, Vendor};
+    use std::time::SystemTime;
+    use tempfile::TempDir;
+
+    /// A JDK install at `location`. The version is `(major, build, minor, security)`
+    pub(super) fn install(location: PathBuf, version: (i64, i64, i64, i64)) -> InstallConfig {
+        InstallConfig {
+            install_location: location,
+            install_time: SystemTime::now().into(),
+            install_settings: InstallSettings {
+                heap_size: HeapSize::Normal,
+                image_type: ImageType::JDK,
+                jvm_impl: AdoptiumJvmImpl::default(),
+                vendor: Vendor::default(),
+                project: Project::JDK,
+                c_lib: None,
+                release_type: ReleaseType::default(),
+                feature_version: version.0,
+            },
+            current_version: version.into(),
+            release_date: None,
+            retained: Vec::new(),
+        }
+    }
+
+    /// Writes the executables `java` and `javac` and a `release` file containing `marker`
+    pub(super) fn write_jdk(location: &Path, marker: &str) {
+        let bin = location.join("bin");
+        std::fs::create_dir_all(&bin).unwrap();
+        for name in ["java", "javac"] {
+            std::fs::write(bin.join(name), "#!/bin/sh\n").unwrap();
+            std::fs::set_permissions(bin.join(name), std::fs::Permissions::from_mode(0o755))
+                .unwrap();
+        }
+        std::fs::write(location.join("release"), marker).unwrap();
+    }
+
+    fn marker(location: &Path) -> String {
+        std::fs::read_to_string(location.join("release")).unwrap()
+    }
+
+    fn symlink_config(root: &Path) -> SysConfig {
+        SysConfig {
+            install_method: InstallMethod::Symlink(Symlink {
+                bin_directory: root.join("bin"),
+                ..Default::default()
+            }),
+        }
+    }
+
+    #[tokio::test]
+    pub async fn swap_and_commit() {
+        let root = TempDir::new().unwrap();
+        let config = install(root.path().join("21-jdk"), (21, 13, 0, 2));
+        write_jdk(&config.install_location, "old");
+        let staging = SysInstaller::staging_directory(&config.install_location);
+        write_jdk(&staging, "new");
+
+        let mut installer = SysInstaller::new(&config, staging.clone());
+        installer.swap_in().await.unwrap();
+        assert_eq!(marker(&config.install_location), "new");
+        let retain =
+            SysInstaller::retained_directory(&config.install_location, &(21, 12, 0, 1).into());
+        installer.commit(Some(&retain)).await.unwrap();
+        assert_eq!(marker(&config.install_location), "new");
+        assert_eq!(marker(&retain), "old");
+        assert!(!staging.exists());
+        assert!(!sibling(&config.install_location, "previous").exists());
+    }
+
+    #[tokio::test]
+    pub async fn failed_swap_restores_the_install() {
+        let root = TempDir::new().unwrap();
+        let config = install(root.path().join("21-jdk"), (21, 13, 0, 2));
+        write_jdk(&config.install_location, "old");
+
+        // Nothing was extracted. So the new data can not be moved into place
+        let mut installer = SysInstaller::new(&config, root.path().join("missing"));
+        assert!(installer.swap_in().await.is_err());
+        assert_eq!(marker(&config.install_location), "old");
+        assert!(!sibling(&config.install_location, "previous").exists());
+    }
+
+    #[tokio::test]
+    pub async fn rollback_restores_the_install() {
+        let root = TempDir::new().unwrap();
+        let config = install(root.path().join("21-jdk"), (21, 13, 0, 2));
+        write_jdk(&config.install_location, "old");
+        let staging = SysInstaller::staging_directory(&config.install_location);
+        write_jdk(&staging, "new");
+
+        let mut installer = SysInstaller::new(&config, staging.clone());
+        installer.swap_in().await.unwrap();
+        installer
+            .rollback(&symlink_config(root.path()))
+            .await
+            .unwrap();
+        assert_eq!(marker(&config.install_location), "old");
+        assert!(!staging.exists());
+        assert!(!sibling(&config.install_location, "previous").exists());
+    }
+
+    #[tokio::test]
+    pub async fn rollback_removes_a_new_install() {
+        let root = TempDir::new().unwrap();
+        let config = install(root.path().join("21-jdk"), (21, 13, 0, 2));
+        let staging = SysInstaller::staging_directory(&config.install_location);
+        write_jdk(&staging, "new");
+
+        let mut installer = SysInstaller::new(&config, staging.clone());
+        installer.swap_in().await.unwrap();
+        installer
+            .rollback(&symlink_config(root.path()))
+            .await
+            .unwrap();
+        assert!(!config.install_location.exists());
+        assert!(!staging.exists());
+    }
+
+    #[tokio::test]
+    pub async fn pruned_versions_are_removed() {
+        let root = TempDir::new().unwrap();
+        let mut config = install(root.path().join("21-jdk"), (21, 13, 0, 3));
+        let retained = |version: (i64, i64, i64, i64)| {
+            let version: VersionData = version.into();
+            let location = SysInstaller::retained_directory(&config.install_location, &version);
+            write_jdk(&location, &version.semver);
+            RetainedVersion {
+                location,
+                version,
+                release_date: None,
+                install_time: SystemTime::now().into(),
+            }
+        };
+        let versions = vec![
+            retained((21, 13, 0, 2)),
+            // Retained before a rollback and updated to again
+            retained((21, 13, 0, 3)),
+            retained((21, 12, 0, 1)),
+            retained((21, 35, 0, 0)),
+            // A stale entry sharing the directory of a kept version
+            retained((21, 13, 0, 2)),
+        ];
+        let locations = versions
+            .iter()
+            .map(|retained| retained.location.clone())
+            .collect::<Vec<_>>();
+        config.retained = versions;
+
+        let pruned = config.prune_retained(2);
+        let kept = config
+            .retained
+            .iter()
+            .map(|retained| retained.version.semver.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(kept, ["21.0.2+13", "21.0.1+12"]);
+        SysInstaller::remove_pruned(&pruned, &config.retained)
+            .await
+            .unwrap();
+        let exists = locations
+            .iter()
+            .map(|location| location.exists())
+            .collect::<Vec<_>>();
+        assert_eq!(exists, [true, false, true, false, true]);
+    }
+}
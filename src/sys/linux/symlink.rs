@@ -0,0 +1,157 @@
+use super::config::Symlink;
+use super::discover_executables;
+use crate::config::get_config_directory;
+use crate::{InstallConfig, InstallerError};
+use futures_util::StreamExt;
+use std::path::Path;
+use tokio::fs::{create_dir_all, read_dir, read_link, remove_file, symlink, symlink_metadata};
+use tokio_stream::wrappers::ReadDirStream;
+
+/// Links the install unless another install is selected
+pub(super) async fn link_if_unselected(
+    config: &Symlink,
+    install: &InstallConfig,
+) -> Result<(), InstallerError> {
+    let master = config.bin_directory.join(&config.master);
+    if symlink_metadata(&master).await.is_ok()
+        && !points_into(&master, &install.install_location).await
+        && is_managed(&master, install).await
+    {
+        return Ok(());
+    }
+    link(config, install).await
+}
+
+/// Links the executables of the install. Replaces the links to any other install.
+///
+/// Nothing is changed if a file that is not managed by adoptiummd is in the way
+pub(super) async fn link(config: &Symlink, install: &InstallConfig) -> Result<(), InstallerError> {
+    let Some(executables) =
+        discover_executables(install, &config.master, |name| config.includes(name)).await?
+    else {
+        return Ok(());
+    };
+    let names = std::iter::once(&config.master)
+        .chain(&executables)
+        .collect::<Vec<_>>();
+    for name in &names {
+        let link = config.bin_directory.join(name);
+        if symlink_metadata(&link).await.is_ok() && !is_managed(&link, install).await {
+            return Err(InstallerError::LinkConflict(link));
+        }
+    }
+
+    if config.bin_directory.exists() {
+        let mut stream = ReadDirStream::new(read_dir(&config.bin_directory).await?);
+        while let Some(value) = stream.next().await {
+            let link = value?.path();
+            if is_managed(&link, install).await {
+                remove_file(&link).await?;
+            }
+        }
+    } else {
+        create_dir_all(&config.bin_directory).await?;
+    }
+    let bin = install.install_location.join("bin");
+    for name in names {
+        symlink(bin.join(name), config.bin_directory.join(name)).await?;
+    }
+    Ok(())
+}
+
+/// Removes the links that point into the install
+pub(super) async fn unlink(
+    config: &Symlink,
+    install: &InstallConfig,
+) -> Result<(), InstallerError> {
+    if !config.bin_directory.exists() {
+        return Ok(());
+    }
+    let mut stream = ReadDirStream::new(read_dir(&config.bin_directory).await?);
+    while let Some(value) = stream.next().await {
+        let link = value?.path();
+        if points_into(&link, &install.install_location).await {
+            remove_file(&link).await?;
+        }
+    }
+    Ok(())
+}
+
+async fn points_into(link: &Path, directory: &Path) -> bool {
+    read_link(link)
+        .await
+        .is_ok_and(|target| target.starts_with(directory))
+}
+
+/// If the link points into `bin` of the install or of another install with a config
+async fn is_managed(link: &Path, install: &InstallConfig) -> bool {
+    let Ok(target) = read_link(link).await else {
+        return false;
+    };
+    let Some(java_home) = target.parent().and_then(Path::parent) else {
+        return false;
+    };
+    if java_home == install.install_location {
+        return true;
+    }
+    let Some(name) = java_home.file_name() else {
+        return false;
+    };
+    java_home.parent() == install.install_location.parent()
+        && get_config_directory()
+            .join("installs")
+            .join(format!("{}.toml", name.to_string_lossy()))
+            .exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::tests::{install, write_jdk};
+    use super::*;
+    use tempfile::TempDir;
+
+    fn config(root: &Path) -> Symlink {
+        Symlink {
+            bin_directory: root.join("bin"),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    pub async fn link_and_unlink() {
+        let root = TempDir::new().unwrap();
+        let install = install(root.path().join("21-jdk"), (21, 13, 0, 2));
+        write_jdk(&install.install_location, "21");
+        let config = config(root.path());
+
+        link(&config, &install).await.unwrap();
+        for name in ["java", "javac"] {
+            assert_eq!(
+                std::fs::read_link(config.bin_directory.join(name)).unwrap(),
+                install.install_location.join("bin").join(name)
+            );
+        }
+        unlink(&config, &install).await.unwrap();
+        assert_eq!(std::fs::read_dir(&config.bin_directory).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    pub async fn unmanaged_file_is_a_conflict() {
+        let root = TempDir::new().unwrap();
+        let install = install(root.path().join("21-jdk"), (21, 13, 0, 2));
+        write_jdk(&install.install_location, "21");
+        let config = config(root.path());
+        std::fs::create_dir_all(&config.bin_directory).unwrap();
+        let javac = config.bin_directory.join("javac");
+        std::fs::write(&javac, "distribution javac").unwrap();
+
+        let result = link(&config, &install).await;
+        assert!(matches!(result, Err(InstallerError::LinkConflict(path)) if path == javac));
+        assert_eq!(
+            std::fs::read_to_string(&javac).unwrap(),
+            "distribution javac"
+        );
+        // `java` was not linked either
+        assert_eq!(std::fs::read_dir(&config.bin_directory).unwrap().count(), 1);
+    }
+}
@@ -1,24 +1,70 @@
 use crate::config::InstallSettings;
-use crate::{InstallerError, ADOPTIUM_USER_AGENT};
-use adoptium_api::requests::release_information::{
-    ReleaseInformationDatum, ReleaseInformationParamsBuilder,
+use crate::InstallerError;
+use adoptium_api::error::AdoptiumError;
+use adoptium_api::requests::latest_assets::{LatestAsset, LatestAssetsParamsBuilder};
+use adoptium_api::requests::release_information::ReleaseInformationParamsBuilder;
+use adoptium_api::requests::release_name::ReleaseNameParamsBuilder;
+use adoptium_api::requests::release_notes::ReleaseNotes;
+use adoptium_api::requests::version_range::{
+    VersionBound, VersionRange, VersionRangeParamsBuilder,
 };
-use adoptium_api::requests::AdoptiumRequest;
-use adoptium_api::types::{SortMethod, SortOrder, SystemProperties, WithSort};
+use adoptium_api::requests::{AdoptiumRequest, PagedAdoptiumRequest, MAX_PAGE_SIZE};
+use adoptium_api::response::VersionData;
+use adoptium_api::types::{ReleaseType, SortMethod, SortOrder, SystemProperties, Vendor, WithSort};
 use adoptium_api::Adoptium;
+use chrono::{DateTime, Utc};
+use tracing::warn;
 
+/// The latest release matching the install settings.
+///
+/// `assets/latest` only has GA builds and can not filter by C library. Those installs use `assets/feature_releases`
 pub async fn get_latest_version(
+    adoptium: &Adoptium,
     config: &InstallSettings,
-) -> Result<ReleaseInformationDatum, InstallerError> {
-    let adoptium = Adoptium::new(ADOPTIUM_USER_AGENT);
+) -> Result<LatestAsset, InstallerError> {
+    if let Vendor::Unknown(vendor) = &config.vendor {
+        return Err(InstallerError::NotUpdatable(vendor.clone()));
+    }
+    if config.release_type != ReleaseType::GeneralAvailability || config.c_lib.is_some() {
+        return get_latest_feature_release(adoptium, config).await;
+    }
+    let system = SystemProperties::default();
+    let request = LatestAssetsParamsBuilder::default()
+        .feature_version(config.feature_version)
+        .jvm_impl(config.jvm_impl.clone())
+        .with_query_builder(|query| {
+            query
+                .architecture(Some(system.architecture))
+                .image_type(Some(config.image_type.clone()))
+                .os(Some(system.os))
+                .vendor(Some(config.vendor.clone()));
+        })
+        .build()
+        .expect("Failed to build LatestAssetsParams");
+
+    adoptium
+        .latest_assets_request(request)
+        .execute()
+        .await?
+        .into_iter()
+        .find(|asset| asset.binary.heap_size == config.heap_size)
+        .ok_or_else(|| not_found(config))
+}
 
+async fn get_latest_feature_release(
+    adoptium: &Adoptium,
+    config: &InstallSettings,
+) -> Result<LatestAsset, InstallerError> {
     let request = ReleaseInformationParamsBuilder::default()
         .feature_version(config.feature_version)
-        .release_type(config.release_type)
+        .release_type(config.release_type.clone())
         .with_query_builder(|query| {
             query
-                .image_type(Some(config.image_type))
-                .jvm_impl(Some(config.jvm_impl))
+                .heap_size(Some(config.heap_size.clone()))
+                .image_type(Some(config.image_type.clone()))
+                .jvm_impl(Some(config.jvm_impl.clone()))
+                .vendor(Some(config.vendor.clone()))
+                .c_lib(config.c_lib.clone())
                 .local_system(Some(SystemProperties::default()))
                 .with_sort(|sort| {
                     sort.sort_order(SortOrder::Descending)
@@ -30,10 +76,116 @@ pub async fn get_latest_version(
         .build()
         .expect("Failed to build ReleaseInformationParams");
 
-    let mut request = adoptium
+    let release = adoptium
         .release_information_request(request)
         .execute()
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| not_found(config))?;
+    let binary = release
+        .binaries
+        .into_iter()
+        .next()
+        .ok_or_else(|| not_found(config))?;
+    Ok(LatestAsset {
+        binary,
+        release_link: release.release_link,
+        release_name: release.release_name,
+        vendor: release.vendor,
+        version: release.version_data,
+    })
+}
+
+/// The latest release if it is newer than `current` and when that was released
+pub async fn get_newer_version(
+    adoptium: &Adoptium,
+    config: &InstallSettings,
+    current: &VersionData,
+) -> Result<Option<(LatestAsset, DateTime<Utc>)>, InstallerError> {
+    let latest = get_latest_version(adoptium, config).await?;
+    if latest.version <= *current {
+        return Ok(None);
+    }
+    let released_on = get_release_date(adoptium, &latest).await?;
+    Ok(Some((latest, released_on)))
+}
+
+/// When the release was published. `assets/latest` only has when its binary was last updated
+pub async fn get_release_date(
+    adoptium: &Adoptium,
+    asset: &LatestAsset,
+) -> Result<DateTime<Utc>, InstallerError> {
+    let request = ReleaseNameParamsBuilder::default()
+        .vendor(asset.vendor.clone())
+        .release_name(asset.release_name.as_str())
+        .with_query_builder(|query| {
+            query
+                .heap_size(Some(asset.binary.heap_size.clone()))
+                .image_type(Some(asset.binary.image_type.clone()))
+                .jvm_impl(Some(asset.binary.jvm_impl.clone()))
+                .local_system(Some(SystemProperties::default()));
+        })
+        .build()
+        .expect("Failed to build ReleaseNameParams");
+    let release = adoptium.release_name_request(request).execute().await?;
+    Ok(release.timestamp)
+}
+
+fn not_found(config: &InstallSettings) -> InstallerError {
+    InstallerError::NoReleaseFound(format!(
+        "{}-{}-{}-{}",
+        config.feature_version, config.image_type, config.heap_size, config.jvm_impl
+    ))
+}
+
+/// Release notes of every release after `from` up to and including `to`
+pub async fn get_release_notes_between(
+    adoptium: &Adoptium,
+    config: &InstallSettings,
+    from: &VersionData,
+    to: &VersionData,
+) -> Result<Vec<ReleaseNotes>, InstallerError> {
+    let range = VersionRange::Range {
+        lower: VersionBound::Exclusive(from.semver.clone()),
+        upper: VersionBound::Inclusive(to.semver.clone()),
+    };
+    let request = VersionRangeParamsBuilder::default()
+        .version(range)
+        .with_query_builder(|query| {
+            query
+                .image_type(Some(config.image_type.clone()))
+                .jvm_impl(Some(config.jvm_impl.clone()))
+                .vendor(Some(config.vendor.clone()))
+                .release_type(Some(config.release_type.clone()))
+                .local_system(Some(SystemProperties::default()))
+                .with_sort(|sort| {
+                    sort.sort_order(SortOrder::Ascending)
+                        .sort_method(SortMethod::Default)
+                        .page(0)
+                        .page_size(MAX_PAGE_SIZE);
+                });
+        })
+        .build()
+        .expect("Failed to build VersionRangeParams");
+    let releases = adoptium
+        .version_range_request(request)
+        .execute_paged()
         .await?;
 
-    Ok(request.remove(0))
+    let mut notes = Vec::with_capacity(releases.len());
+    for release in releases {
+        match adoptium
+            .release_notes_request(&release.release_name, release.vendor)
+            .execute()
+            .await
+        {
+            Ok(value) => notes.push(value),
+            Err(AdoptiumError::NotFound(_)) => {
+                warn!(release = release.release_name, "No release notes found");
+            }
+            Err(error) => return Err(error.into()),
+        }
+    }
+    Ok(notes)
 }